@@ -16,7 +16,6 @@ export type MultisigLite = {
     "use std::rc::Rc;",
     "",
     "use solana_sdk::commitment_config::CommitmentConfig;",
-    "use solana_sdk::instruction::AccountMeta;",
     "use solana_sdk::pubkey::Pubkey;",
     "use solana_sdk::signature::read_keypair_file;",
     "use solana_sdk::signer::Signer;",
@@ -33,45 +32,32 @@ export type MultisigLite = {
     "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
     "",
     "// Gets the PDAs.",
-    "let (state_pda, state_bump) =",
-    "Pubkey::find_program_address(&[b\"state\", signer.pubkey().as_ref()], &pid);",
-    "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+    "let index = 0u64; // the first multisig account of the funder.",
+    "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+    "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+    "&pid,",
+    ");",
     "",
-    "// Gets the pending transfers and the recipients account info.",
-    "let mut remaining_accounts = vec![];",
+    "// Approves the pending transfers one by one.",
     "let state: multisig_lite::State = program.account(state_pda)?;",
     "for transfer_pubkey in state.queue {",
-    "let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;",
-    "",
-    "// Pushes the transfer account.",
-    "remaining_accounts.push(AccountMeta {",
-    "pubkey: transfer_pubkey,",
-    "is_signer: false,",
-    "is_writable: true,",
-    "});",
-    "",
-    "// Pushes the recipient account.",
-    "remaining_accounts.push(AccountMeta {",
-    "pubkey: transfer.recipient,",
-    "is_signer: false,",
-    "is_writable: true,",
-    "});",
+    "if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {",
+    "continue; // Skips the proposals.",
     "}",
     "",
-    "// Approve the multisig account.",
     "let sig = program",
     ".request()",
     ".accounts(multisig_lite::accounts::Approve {",
     "signer: signer.pubkey(),",
     "state: state_pda,",
-    "fund: fund_pda,",
+    "transfer: transfer_pubkey,",
     "})",
-    ".args(multisig_lite::instruction::Approve { fund_bump })",
-    ".accounts(remaining_accounts)",
+    ".args(multisig_lite::instruction::Approve {})",
     ".signer(signer.as_ref())",
     ".send()?;",
     "",
     "println!(\"{sig}\");",
+    "}",
     "# Ok(())",
     "# }",
     "```"
@@ -82,8 +68,21 @@ export type MultisigLite = {
       "docs": [
         "Creates a multisig account.",
         "",
-        "It's restricted one multisig account to each funder Pubkey,",
-        "as it's used for the multisig PDA address generation.",
+        "The funder can create multiple multisig accounts with the",
+        "different index, as both the funder Pubkey and the index",
+        "are used for the multisig PDA address generation.  Other",
+        "instructions, except [`multisig_lite::fund`] and",
+        "[`multisig_lite::close`], locate the multisig account by",
+        "the address.",
+        "",
+        "Each signer votes with the weight given at the same position",
+        "in the `weights` array, and the transfers and proposals are",
+        "approved once the summed weight of the approvals reaches the",
+        "threshold, `m`.  The threshold should be reachable by the",
+        "signers.",
+        "",
+        "The signers are kept in the given order, and the duplicate",
+        "signers are rejected.",
         "",
         "# Examples",
         "",
@@ -110,8 +109,11 @@ export type MultisigLite = {
         "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
         "// Creates a multisig account.",
@@ -126,7 +128,9 @@ export type MultisigLite = {
         ".args(multisig_lite::instruction::Create {",
         "m: 2, // m as in m/n.",
         "signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],",
+        "weights: vec![2, 1, 1], // the funder's vote counts twice.",
         "q: 10, // transfer queue limit.",
+        "_index: index,",
         "_state_bump: state_bump,",
         "fund_bump,",
         "})",
@@ -184,10 +188,18 @@ export type MultisigLite = {
             "vec": "publicKey"
           }
         },
+        {
+          "name": "weights",
+          "type": "bytes"
+        },
         {
           "name": "q",
           "type": "u8"
         },
+        {
+          "name": "index",
+          "type": "u64"
+        },
         {
           "name": "stateBump",
           "type": "u8"
@@ -204,6 +216,7 @@ export type MultisigLite = {
         "Funds lamports to the multisig fund account.",
         "",
         "The funding is only allowed by the multisig account funder.",
+        "Other accounts can deposit through [`multisig_lite::deposit`].",
         "",
         "# Examples",
         "",
@@ -231,8 +244,11 @@ export type MultisigLite = {
         "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
         "// Funds the multisig account.",
@@ -246,6 +262,7 @@ export type MultisigLite = {
         "})",
         ".args(multisig_lite::instruction::Fund {",
         "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
+        "_index: index,",
         "_state_bump: state_bump,",
         "fund_bump,",
         "})",
@@ -265,7 +282,8 @@ export type MultisigLite = {
           "docs": [
             "A funder of the account.",
             "",
-            "The funding is only allowed by the multisig account creator."
+            "The funding is only allowed by the multisig account creator.",
+            "Please use [`multisig_lite::deposit`] for other payers."
           ]
         },
         {
@@ -299,6 +317,10 @@ export type MultisigLite = {
           "name": "lamports",
           "type": "u64"
         },
+        {
+          "name": "index",
+          "type": "u64"
+        },
         {
           "name": "stateBump",
           "type": "u8"
@@ -310,16 +332,16 @@ export type MultisigLite = {
       ]
     },
     {
-      "name": "createTransfer",
+      "name": "deposit",
       "docs": [
-        "Creates a queued transfer lamports to the recipient.",
+        "Deposits lamports to the multisig fund account.",
         "",
-        "Transfer account creation fee will be given back to the",
-        "creator of the transfer from the multisig fund.",
+        "Unlike [`multisig_lite::fund`], any payer can deposit lamports",
+        "to the multisig account, located by the address.",
         "",
         "# Examples",
         "",
-        "Here is how to create a pending transfer on Devnet:",
+        "Here is how to deposit to the multisig account on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
@@ -328,49 +350,38 @@ export type MultisigLite = {
         "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
-        "use solana_sdk::signer::{keypair::Keypair, Signer};",
+        "use solana_sdk::signer::Signer;",
         "use solana_sdk::system_program;",
         "",
         "use anchor_client::{Client, Cluster};",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
+        "let payer = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
         "",
-        "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "// The multisig account to deposit, shared by the multisig signers.",
+        "let state_pda = Pubkey::new_unique();",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Temporary transfer keypair.",
-        "//",
-        "// This is only required for the transaction signature and",
-        "// won't be required once the transaction is recorded on the",
-        "// ledger.",
-        "let transfer = Keypair::new();",
-        "",
-        "// Creates a pending transfer.",
+        "// Deposits to the multisig account.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::CreateTransfer {",
-        "creator: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::Deposit {",
+        "payer: payer.pubkey(),",
         "state: state_pda,",
         "fund: fund_pda,",
-        "transfer: transfer.pubkey(),",
         "system_program: system_program::id(),",
         "})",
-        ".args(multisig_lite::instruction::CreateTransfer {",
-        "recipient: Pubkey::new_unique(),",
-        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
+        ".args(multisig_lite::instruction::Deposit {",
+        "lamports: 10 * LAMPORTS_PER_SOL,",
         "fund_bump,",
         "})",
-        ".signer(funder.as_ref())",
-        ".signer(&transfer)",
+        ".signer(payer.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
@@ -380,13 +391,13 @@ export type MultisigLite = {
       ],
       "accounts": [
         {
-          "name": "creator",
+          "name": "payer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "An initiator of the fund transfer.",
+            "A payer of the deposit.",
             "",
-            "It should be one of the signers of the multisig account."
+            "Any account can deposit to the multisig account."
           ]
         },
         {
@@ -402,32 +413,20 @@ export type MultisigLite = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "A multisig fund PDA account.",
+            "A multisig fund account.",
             ""
           ]
         },
-        {
-          "name": "transfer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "A transfer account to keep the queued transfer info."
-          ]
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The system program to create a transfer account."
+            "The system program to make the transfer of the fund."
           ]
         }
       ],
       "args": [
-        {
-          "name": "recipient",
-          "type": "publicKey"
-        },
         {
           "name": "lamports",
           "type": "u64"
@@ -439,20 +438,24 @@ export type MultisigLite = {
       ]
     },
     {
-      "name": "approve",
+      "name": "syncBalance",
       "docs": [
-        "Approves the transactions and executes the transfer",
-        "in case the `m` approvals are met.",
+        "Synchronizes the multisig balance with the fund account lamports.",
+        "",
+        "The lamports directly transferred to the fund account, e.g.",
+        "by the system program transfer, are not accounted in the",
+        "multisig balance.  This permissionless instruction recomputes",
+        "the balance as the fund account lamports minus the rent-exempt",
+        "minimum and the lamports reserved by the pending transfers.",
         "",
         "# Examples",
         "",
-        "Here is how to approve pending transfers on Devnet:",
+        "Here is how to synchronize the multisig balance on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
         "",
         "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::instruction::AccountMeta;",
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
@@ -461,50 +464,26 @@ export type MultisigLite = {
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let signer = Rc::new(read_keypair_file(",
+        "let payer = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
         "",
-        "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", signer.pubkey().as_ref()], &pid);",
+        "// The multisig account to synchronize.",
+        "let state_pda = Pubkey::new_unique();",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Gets the pending transfers and the recipients account info.",
-        "let mut remaining_accounts = vec![];",
-        "let state: multisig_lite::State = program.account(state_pda)?;",
-        "for transfer_pubkey in state.queue {",
-        "let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;",
-        "",
-        "// Pushes the transfer account.",
-        "remaining_accounts.push(AccountMeta {",
-        "pubkey: transfer_pubkey,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "});",
-        "",
-        "// Pushes the recipient account.",
-        "remaining_accounts.push(AccountMeta {",
-        "pubkey: transfer.recipient,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "});",
-        "}",
-        "",
-        "// Approve the multisig account.",
+        "// Synchronizes the multisig balance.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Approve {",
-        "signer: signer.pubkey(),",
+        ".accounts(multisig_lite::accounts::SyncBalance {",
         "state: state_pda,",
         "fund: fund_pda,",
         "})",
-        ".args(multisig_lite::instruction::Approve { fund_bump })",
-        ".accounts(remaining_accounts)",
-        ".signer(signer.as_ref())",
+        ".args(multisig_lite::instruction::SyncBalance { fund_bump })",
+        ".signer(payer.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
@@ -513,14 +492,6 @@ export type MultisigLite = {
         "```"
       ],
       "accounts": [
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "An approver of the current state of the multisg account."
-          ]
-        },
         {
           "name": "state",
           "isMut": true,
@@ -547,25 +518,37 @@ export type MultisigLite = {
       ]
     },
     {
-      "name": "close",
+      "name": "createTransfer",
       "docs": [
-        "Closes the multisig account.",
+        "Creates a queued transfer lamports to the recipient.",
         "",
-        "It cleans up all the remaining accounts and return those rents",
-        "back to the funder, original creator of the multisig account.",
+        "The transfer account is a PDA of the multisig state and the",
+        "[`State::transfers`] index, and its creation fee will be given",
+        "back to the creator of the transfer from the multisig fund.",
+        "The [`State::balance`] should cover both the transfer lamports",
+        "and the creation fee, which goes back to the balance once the",
+        "transfer account is closed.",
+        "The optional `execute_after` unix timestamp time-locks the",
+        "transfer execution, so that the signers can notice and",
+        "cancel the suspicious transfer.  The optional `expire_at`",
+        "unix timestamp voids the transfer after the deadline.",
+        "The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, tells",
+        "what the transfer is for and is recorded through the SPL Memo",
+        "program at the execution.",
         "",
         "# Examples",
         "",
-        "Here is how you close the multisig account on devnet:",
+        "Here is how to create a pending transfer on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
         "",
         "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
         "",
         "use anchor_client::{Client, Cluster};",
         "",
@@ -579,32 +562,36 @@ export type MultisigLite = {
         "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Gets the remaining transfers to collects the rents.",
+        "// Gets the next transfer PDA.",
         "let state: multisig_lite::State = program.account(state_pda)?;",
-        "let remaining_accounts: Vec<_> = state",
-        ".queue",
-        ".into_iter()",
-        ".map(|pubkey| AccountMeta {",
-        "pubkey,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "})",
-        ".collect();",
+        "let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(",
+        "&[b\"transfer\", state_pda.as_ref(), &state.transfers.to_le_bytes()],",
+        "&pid,",
+        ");",
         "",
-        "// close the multisig account.",
+        "// Creates a pending transfer.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Close {",
-        "funder: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::CreateTransfer {",
+        "creator: funder.pubkey(),",
         "state: state_pda,",
         "fund: fund_pda,",
+        "transfer: transfer_pda,",
+        "system_program: system_program::id(),",
         "})",
-        ".args(multisig_lite::instruction::Close {",
-        "_state_bump: state_bump,",
+        ".args(multisig_lite::instruction::CreateTransfer {",
+        "recipient: Pubkey::new_unique(),",
+        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
+        "execute_after: None,",
+        "expire_at: None,",
+        "memo: Some(\"Invoice #1234\".to_string()),",
         "fund_bump,",
         "})",
         ".signer(funder.as_ref())",
@@ -617,11 +604,13 @@ export type MultisigLite = {
       ],
       "accounts": [
         {
-          "name": "funder",
+          "name": "creator",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "An original funder of the multisig account."
+            "An initiator of the fund transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
           ]
         },
         {
@@ -640,29 +629,77 @@ export type MultisigLite = {
             "A multisig fund PDA account.",
             ""
           ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A transfer PDA account to keep the queued transfer info."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a transfer account."
+          ]
         }
       ],
       "args": [
         {
-          "name": "stateBump",
-          "type": "u8"
+          "name": "recipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "executeAfter",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "expireAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          }
         },
         {
           "name": "fundBump",
           "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "state",
+      "name": "createTokenTransfer",
       "docs": [
-        "A multisig [`State`] PDA account data.",
+        "Creates a queued token transfer to the recipient.",
+        "",
+        "The tokens are transferred from the multisig fund associated",
+        "token account of the mint, which should be created before",
+        "through the associated token account program.  The token",
+        "balance should cover the amount on top of the other pending",
+        "token transfers of the mint, kept in [`State::pending_tokens`].",
+        "The transfer account is a PDA of the multisig state and the",
+        "[`State::transfers`] index, and its creation fee will be given",
+        "back to the creator of the transfer from the multisig fund.",
+        "The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, is",
+        "recorded through the SPL Memo program at the execution, as",
+        "[`multisig_lite::create_transfer`] does.",
         "",
         "# Examples",
         "",
-        "Here is how to query the [`State`] PDA account on Devnet.",
+        "Here is how to create a pending token transfer on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
@@ -671,8 +708,10 @@ export type MultisigLite = {
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
         "",
         "use anchor_client::{Client, Cluster};",
+        "use anchor_spl::associated_token::get_associated_token_address;",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
@@ -684,311 +723,159 @@ export type MultisigLite = {
         "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, _state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Query the `multisig_lite::State` account.",
+        "// The token mint and the fund associated token account.",
+        "let mint = Pubkey::new_unique();",
+        "let fund_token = get_associated_token_address(&fund_pda, &mint);",
+        "",
+        "// Gets the next transfer PDA.",
         "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(",
+        "&[b\"transfer\", state_pda.as_ref(), &state.transfers.to_le_bytes()],",
+        "&pid,",
+        ");",
         "",
-        "// Print out the state account.",
-        "println!(\"{state:?}\");",
+        "// Creates a pending token transfer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateTokenTransfer {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "mint,",
+        "fund_token,",
+        "transfer: transfer_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateTokenTransfer {",
+        "recipient: Pubkey::new_unique(),",
+        "amount: 1_000,",
+        "execute_after: None,",
+        "expire_at: None,",
+        "memo: Some(\"Invoice #1234\".to_string()),",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
         "# Ok(())",
         "# }",
         "```"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "m",
-            "docs": [
-              "A threshold."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "signers",
-            "docs": [
-              "An array of signers Pubkey."
-            ],
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "signed",
-            "docs": [
-              "A current signed state."
-            ],
-            "type": {
-              "vec": "bool"
-            }
-          },
-          {
-            "name": "fund",
-            "docs": [
-              "A fund PDA account, holding the native SOL."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "balance",
-            "docs": [
-              "A balance of the fund in lamports."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "q",
-            "docs": [
-              "A limit of the pending transactions."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "queue",
-            "docs": [
-              "An array of the pending transactions."
-            ],
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "transfer",
-      "docs": [
-        "A multisig [`Transfer`] account data.",
-        "",
-        "# Examples",
-        "",
-        "Here is how to query the [`Transfer`] PDA account on Devnet.",
-        "",
-        "```no_run",
-        "use std::rc::Rc;",
-        "",
-        "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::pubkey::Pubkey;",
-        "use solana_sdk::signature::read_keypair_file;",
-        "use solana_sdk::signer::Signer;",
-        "",
-        "use anchor_client::{Client, Cluster};",
-        "",
-        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
-        "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
-        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
-        ")?);",
-        "let opts = CommitmentConfig::processed();",
-        "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
-        "",
-        "// Gets the PDAs.",
-        "let (state_pda, _state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
-        "",
-        "// Query the `multisig_lite::State` account to get the queued transfers.",
-        "let state: multisig_lite::State = program.account(state_pda)?;",
-        "",
-        "// Query the `multisig_lite::Transfer` accounts iteratively.",
-        "for transfer in state.queue {",
-        "let transfer: multisig_lite::Transfer = program.account(transfer)?;",
-        "println!(\"{transfer:?}\");",
-        "}",
-        "# Ok(())",
-        "# }",
-        "```"
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the token transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A token mint of the transfer."
+          ]
+        },
+        {
+          "name": "fundToken",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund associated token account of the mint."
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A transfer PDA account to keep the queued transfer info."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a transfer account."
+          ]
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "docs": [
-              "An creator of the transfer, one of the multisig",
-              "signers."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "recipient",
-            "docs": [
-              "A recipient of the transfer."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "lamports",
-            "docs": [
-              "A lamports to transfer."
-            ],
-            "type": "u64"
+      "args": [
+        {
+          "name": "recipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "executeAfter",
+          "type": {
+            "option": "i64"
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "AccountEmpty",
-      "msg": "Multisig account is empty. Please create transactions"
-    },
-    {
-      "code": 6001,
-      "name": "AccountFull",
-      "msg": "Multisig transaction queue is full. Please approve those."
-    },
-    {
-      "code": 6002,
-      "name": "AccountLocked",
-      "msg": "Multisig account is locked. Please approve the transactions"
-    },
-    {
-      "code": 6003,
-      "name": "MissingRecipientAccountInfo",
-      "msg": "Missing transfer recipient AccountInfo"
-    },
-    {
-      "code": 6004,
-      "name": "FundAccountNotWritable",
-      "msg": "Fund account is not writable"
-    },
-    {
-      "code": 6005,
-      "name": "FundAccountIsNotEmpty",
-      "msg": "Fund account data is not empty"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidFundAddress",
-      "msg": "Invalid fund account"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidFundBumpSeed",
-      "msg": "Invalid fund bump seed"
-    },
-    {
-      "code": 6008,
-      "name": "NoSigners",
-      "msg": "No signers provided"
-    },
-    {
-      "code": 6009,
-      "name": "TooManySigners",
-      "msg": "Too many signers provided"
-    },
-    {
-      "code": 6010,
-      "name": "ThresholdTooHigh",
-      "msg": "Threshold too high"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidSigner",
-      "msg": "Invalid signer"
+        },
+        {
+          "name": "expireAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "code": 6012,
-      "name": "NotEnoughFundBalance",
-      "msg": "There is not enough fund balance"
-    }
-  ]
-};
-
-export const IDL: MultisigLite = {
-  "version": "0.0.8",
-  "name": "multisig_lite",
-  "docs": [
-    "Module representing the program instruction handlers.",
-    "",
-    "# Examples",
-    "",
-    "Here is how to approve pending transfers on Devnet.",
-    "",
-    "Please take a look at the individual functions for other",
-    "instruction opperations. e.g. [`multisig_lite::fund`] for",
-    "how to fund the multisig account.",
-    "",
-    "```no_run",
-    "use std::rc::Rc;",
-    "",
-    "use solana_sdk::commitment_config::CommitmentConfig;",
-    "use solana_sdk::instruction::AccountMeta;",
-    "use solana_sdk::pubkey::Pubkey;",
-    "use solana_sdk::signature::read_keypair_file;",
-    "use solana_sdk::signer::Signer;",
-    "",
-    "use anchor_client::{Client, Cluster};",
-    "",
-    "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
-    "let url = Cluster::Devnet;",
-    "let signer = Rc::new(read_keypair_file(",
-    "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
-    ")?);",
-    "let opts = CommitmentConfig::processed();",
-    "let pid = multisig_lite::id();",
-    "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
-    "",
-    "// Gets the PDAs.",
-    "let (state_pda, state_bump) =",
-    "Pubkey::find_program_address(&[b\"state\", signer.pubkey().as_ref()], &pid);",
-    "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
-    "",
-    "// Gets the pending transfers and the recipients account info.",
-    "let mut remaining_accounts = vec![];",
-    "let state: multisig_lite::State = program.account(state_pda)?;",
-    "for transfer_pubkey in state.queue {",
-    "let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;",
-    "",
-    "// Pushes the transfer account.",
-    "remaining_accounts.push(AccountMeta {",
-    "pubkey: transfer_pubkey,",
-    "is_signer: false,",
-    "is_writable: true,",
-    "});",
-    "",
-    "// Pushes the recipient account.",
-    "remaining_accounts.push(AccountMeta {",
-    "pubkey: transfer.recipient,",
-    "is_signer: false,",
-    "is_writable: true,",
-    "});",
-    "}",
-    "",
-    "// Approve the multisig account.",
-    "let sig = program",
-    ".request()",
-    ".accounts(multisig_lite::accounts::Approve {",
-    "signer: signer.pubkey(),",
-    "state: state_pda,",
-    "fund: fund_pda,",
-    "})",
-    ".args(multisig_lite::instruction::Approve { fund_bump })",
-    ".accounts(remaining_accounts)",
-    ".signer(signer.as_ref())",
-    ".send()?;",
-    "",
-    "println!(\"{sig}\");",
-    "# Ok(())",
-    "# }",
-    "```"
-  ],
-  "instructions": [
-    {
-      "name": "create",
+      "name": "cancelTransfer",
       "docs": [
-        "Creates a multisig account.",
+        "Cancels a queued transfer.",
         "",
-        "It's restricted one multisig account to each funder Pubkey,",
-        "as it's used for the multisig PDA address generation.",
+        "It gives the transfer lamports back to the multisig balance",
+        "and closes the transfer account.  The creator of the transfer",
+        "can cancel it by itself.  Otherwise, it requires the m-of-n",
+        "quorum, passed as the signer remaining accounts.",
         "",
         "# Examples",
         "",
-        "Here is how you create a multisig account on Devnet:",
+        "Here is how to cancel the first pending transfer on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
@@ -997,41 +884,41 @@ export const IDL: MultisigLite = {
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
-        "use solana_sdk::system_program;",
         "",
         "use anchor_client::{Client, Cluster};",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
+        "let creator = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Creates a multisig account.",
+        "// Gets the first pending transfer.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let transfer = state.queue[0];",
+        "",
+        "// Cancels the transfer.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Create {",
-        "funder: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::CancelTransfer {",
+        "signer: creator.pubkey(),",
         "state: state_pda,",
         "fund: fund_pda,",
-        "system_program: system_program::id(),",
+        "transfer,",
         "})",
-        ".args(multisig_lite::instruction::Create {",
-        "m: 2, // m as in m/n.",
-        "signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],",
-        "q: 10, // transfer queue limit.",
-        "_state_bump: state_bump,",
-        "fund_bump,",
-        "})",
-        ".signer(funder.as_ref())",
+        ".args(multisig_lite::instruction::CancelTransfer { fund_bump })",
+        ".signer(creator.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
@@ -1041,11 +928,13 @@ export const IDL: MultisigLite = {
       ],
       "accounts": [
         {
-          "name": "funder",
+          "name": "signer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "A funder of the multisig account."
+            "A canceler of the transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
           ]
         },
         {
@@ -1061,38 +950,22 @@ export const IDL: MultisigLite = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "A multisig fund account.",
+            "A multisig fund PDA account.",
             ""
           ]
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "transfer",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The system program to create a multisig PDA accounts."
+            "A queued transfer account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
           ]
         }
       ],
       "args": [
-        {
-          "name": "m",
-          "type": "u8"
-        },
-        {
-          "name": "signers",
-          "type": {
-            "vec": "publicKey"
-          }
-        },
-        {
-          "name": "q",
-          "type": "u8"
-        },
-        {
-          "name": "stateBump",
-          "type": "u8"
-        },
         {
           "name": "fundBump",
           "type": "u8"
@@ -1100,194 +973,190 @@ export const IDL: MultisigLite = {
       ]
     },
     {
-      "name": "fund",
+      "name": "approve",
       "docs": [
-        "Funds lamports to the multisig fund account.",
+        "Approves the queued transfer.",
         "",
-        "The funding is only allowed by the multisig account funder.",
+        "It only records the approval of the signer.  The transfer is",
+        "executed by [`multisig_lite::execute`], or by",
+        "[`multisig_lite::execute_token`] for the token transfer,",
+        "once it meets the m number of signers approval.",
+        "The expired transfer can't be approved and will be pruned",
+        "by [`multisig_lite::prune_expired`].",
+        "",
+        "Approving the same transfer again is a no-op.  The transfer",
+        "not in the multisig queue is rejected with",
+        "[`Error::TransferNotQueued`], instead of being silently skipped.",
         "",
         "# Examples",
         "",
-        "Here is how to fund to the multisig account on Devnet:",
+        "Here is how to approve pending transfers on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
         "",
         "use solana_sdk::commitment_config::CommitmentConfig;",
         "use solana_sdk::pubkey::Pubkey;",
-        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
-        "use solana_sdk::system_program;",
         "",
         "use anchor_client::{Client, Cluster};",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
+        "let signer = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
-        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending transfers one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for transfer_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {",
+        "continue; // Skips the proposals.",
+        "}",
         "",
-        "// Funds the multisig account.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Fund {",
-        "funder: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::Approve {",
+        "signer: signer.pubkey(),",
         "state: state_pda,",
-        "fund: fund_pda,",
-        "system_program: system_program::id(),",
-        "})",
-        ".args(multisig_lite::instruction::Fund {",
-        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
-        "_state_bump: state_bump,",
-        "fund_bump,",
+        "transfer: transfer_pubkey,",
         "})",
-        ".signer(funder.as_ref())",
+        ".args(multisig_lite::instruction::Approve {})",
+        ".signer(signer.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
+        "}",
         "# Ok(())",
         "# }",
         "```"
       ],
       "accounts": [
         {
-          "name": "funder",
+          "name": "signer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "A funder of the account.",
-            "",
-            "The funding is only allowed by the multisig account creator."
+            "An approver of the transfer."
           ]
         },
         {
           "name": "state",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "docs": [
             "A multisig state PDA account."
           ]
         },
         {
-          "name": "fund",
+          "name": "transfer",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "A multisig fund account.",
-            ""
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The system program to make the transfer of the fund."
+            "A queued transfer account to approve."
           ]
         }
       ],
-      "args": [
-        {
-          "name": "lamports",
-          "type": "u64"
-        },
-        {
-          "name": "stateBump",
-          "type": "u8"
-        },
-        {
-          "name": "fundBump",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createTransfer",
+      "name": "execute",
       "docs": [
-        "Creates a queued transfer lamports to the recipient.",
+        "Executes the approved transfer.",
         "",
-        "Transfer account creation fee will be given back to the",
-        "creator of the transfer from the multisig fund.",
+        "The signer with the [`Role::EXECUTOR`] role can execute the",
+        "transfer once it meets the m number of signers approval, which",
+        "makes it possible to batch the execution by an executor bot.",
+        "The time-locked transfer can't be executed until the",
+        "`execute_after` timestamp.",
         "",
         "# Examples",
         "",
-        "Here is how to create a pending transfer on Devnet:",
+        "Here is how to execute the approved transfers on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
         "",
         "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
-        "use solana_sdk::signer::{keypair::Keypair, Signer};",
-        "use solana_sdk::system_program;",
+        "use solana_sdk::signer::Signer;",
         "",
         "use anchor_client::{Client, Cluster};",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
+        "let executor = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Temporary transfer keypair.",
-        "//",
-        "// This is only required for the transaction signature and",
-        "// won't be required once the transaction is recorded on the",
-        "// ledger.",
-        "let transfer = Keypair::new();",
+        "// Executes the approved transfers one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for transfer_pubkey in &state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "let approvals: usize = transfer",
+        ".signed",
+        ".iter()",
+        ".zip(&state.weights)",
+        ".filter(|(signed, _)| **signed)",
+        ".map(|(_, weight)| *weight as usize)",
+        ".sum();",
+        "if transfer.mint.is_some() || approvals < state.m as usize {",
+        "continue;",
+        "}",
         "",
-        "// Creates a pending transfer.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::CreateTransfer {",
-        "creator: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::Execute {",
+        "executor: executor.pubkey(),",
         "state: state_pda,",
         "fund: fund_pda,",
-        "transfer: transfer.pubkey(),",
-        "system_program: system_program::id(),",
-        "})",
-        ".args(multisig_lite::instruction::CreateTransfer {",
-        "recipient: Pubkey::new_unique(),",
-        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
-        "fund_bump,",
+        "transfer: *transfer_pubkey,",
+        "recipient: transfer.recipient,",
+        "memo_program: multisig_lite::memo::ID,",
         "})",
-        ".signer(funder.as_ref())",
-        ".signer(&transfer)",
+        ".args(multisig_lite::instruction::Execute { fund_bump })",
+        ".signer(executor.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
+        "}",
         "# Ok(())",
         "# }",
         "```"
       ],
       "accounts": [
         {
-          "name": "creator",
-          "isMut": true,
+          "name": "executor",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "An initiator of the fund transfer.",
-            "",
-            "It should be one of the signers of the multisig account."
+            "An executor of the multisig account."
           ]
         },
         {
@@ -1303,36 +1172,40 @@ export const IDL: MultisigLite = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "A multisig fund PDA account.",
+            "A multisig fund account.",
             ""
           ]
         },
         {
           "name": "transfer",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "A transfer account to keep the queued transfer info."
+            "A queued transfer account to execute.",
+            "",
+            "The token transfer is executed by [`multisig_lite::execute_token`].",
+            "It's closed and the rent goes back to the multisig fund."
           ]
         },
         {
-          "name": "systemProgram",
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient of the transfer.",
+            ""
+          ]
+        },
+        {
+          "name": "memoProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The system program to create a transfer account."
+            "The SPL Memo program to record the transfer memo."
           ]
         }
       ],
       "args": [
-        {
-          "name": "recipient",
-          "type": "publicKey"
-        },
-        {
-          "name": "lamports",
-          "type": "u64"
-        },
         {
           "name": "fundBump",
           "type": "u8"
@@ -1340,86 +1213,101 @@ export const IDL: MultisigLite = {
       ]
     },
     {
-      "name": "approve",
+      "name": "executeToken",
       "docs": [
-        "Approves the transactions and executes the transfer",
-        "in case the `m` approvals are met.",
+        "Executes the approved token transfer.",
+        "",
+        "The tokens are transferred from the multisig fund associated",
+        "token account to the recipient token account, signed by the",
+        "multisig fund PDA.  The signer with the [`Role::EXECUTOR`] role",
+        "can execute the token transfer once it meets the m number of",
+        "signers approval.  The memo of the transfer, if any, is recorded",
+        "through the SPL Memo program.",
         "",
         "# Examples",
         "",
-        "Here is how to approve pending transfers on Devnet:",
+        "Here is how to execute the approved token transfers on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
         "",
         "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::instruction::AccountMeta;",
         "use solana_sdk::pubkey::Pubkey;",
         "use solana_sdk::signature::read_keypair_file;",
         "use solana_sdk::signer::Signer;",
         "",
         "use anchor_client::{Client, Cluster};",
+        "use anchor_spl::associated_token::get_associated_token_address;",
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let signer = Rc::new(read_keypair_file(",
+        "let executor = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
         "",
         "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", signer.pubkey().as_ref()], &pid);",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Gets the pending transfers and the recipients account info.",
-        "let mut remaining_accounts = vec![];",
+        "// Executes the approved token transfers one by one.",
         "let state: multisig_lite::State = program.account(state_pda)?;",
-        "for transfer_pubkey in state.queue {",
-        "let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;",
-        "",
-        "// Pushes the transfer account.",
-        "remaining_accounts.push(AccountMeta {",
-        "pubkey: transfer_pubkey,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "});",
-        "",
-        "// Pushes the recipient account.",
-        "remaining_accounts.push(AccountMeta {",
-        "pubkey: transfer.recipient,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "});",
+        "for transfer_pubkey in &state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "let mint = match transfer.mint {",
+        "Some(mint) => mint,",
+        "None => continue,",
+        "};",
+        "let approvals: usize = transfer",
+        ".signed",
+        ".iter()",
+        ".zip(&state.weights)",
+        ".filter(|(signed, _)| **signed)",
+        ".map(|(_, weight)| *weight as usize)",
+        ".sum();",
+        "if approvals < state.m as usize {",
+        "continue;",
         "}",
         "",
-        "// Approve the multisig account.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Approve {",
-        "signer: signer.pubkey(),",
+        ".accounts(multisig_lite::accounts::ExecuteToken {",
+        "executor: executor.pubkey(),",
         "state: state_pda,",
         "fund: fund_pda,",
+        "transfer: *transfer_pubkey,",
+        "mint,",
+        "fund_token: get_associated_token_address(&fund_pda, &mint),",
+        "recipient_token: get_associated_token_address(&transfer.recipient, &mint),",
+        "token_program: anchor_spl::token::ID,",
+        "memo_program: multisig_lite::memo::ID,",
         "})",
-        ".args(multisig_lite::instruction::Approve { fund_bump })",
-        ".accounts(remaining_accounts)",
-        ".signer(signer.as_ref())",
+        ".args(multisig_lite::instruction::ExecuteToken { fund_bump })",
+        ".signer(executor.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
+        "}",
         "# Ok(())",
         "# }",
         "```"
       ],
       "accounts": [
         {
-          "name": "signer",
-          "isMut": true,
+          "name": "executor",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "An approver of the current state of the multisg account."
+            "An executor of the multisig account."
           ]
         },
         {
@@ -1438,6 +1326,56 @@ export const IDL: MultisigLite = {
             "A multisig fund account.",
             ""
           ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued token transfer account to execute.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A token mint of the transfer."
+          ]
+        },
+        {
+          "name": "fundToken",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund associated token account of the mint."
+          ]
+        },
+        {
+          "name": "recipientToken",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient token account of the transfer."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to make the token transfer."
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The SPL Memo program to record the transfer memo."
+          ]
         }
       ],
       "args": [
@@ -1448,16 +1386,18 @@ export const IDL: MultisigLite = {
       ]
     },
     {
-      "name": "close",
+      "name": "pruneExpired",
       "docs": [
-        "Closes the multisig account.",
+        "Prunes the expired transfers.",
         "",
-        "It cleans up all the remaining accounts and return those rents",
-        "back to the funder, original creator of the multisig account.",
+        "Anyone can prune the expired transfers, passed as the writable",
+        "remaining accounts.  It closes the transfer accounts by giving",
+        "back the rent fee to the multisig fund, and restores the",
+        "reserved lamports to the multisig balance.",
         "",
         "# Examples",
         "",
-        "Here is how you close the multisig account on devnet:",
+        "Here is how to prune the expired transfers on Devnet:",
         "",
         "```no_run",
         "use std::rc::Rc;",
@@ -1472,43 +1412,43 @@ export const IDL: MultisigLite = {
         "",
         "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
         "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
+        "let payer = Rc::new(read_keypair_file(",
         "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
         ")?);",
         "let opts = CommitmentConfig::processed();",
         "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
         "",
-        "// Gets the PDAs.",
-        "let (state_pda, state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
+        "// The multisig account to prune the expired transfers.",
+        "let state_pda = Pubkey::new_unique();",
         "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
         "",
-        "// Gets the remaining transfers to collects the rents.",
+        "// Gets the expired transfers.",
+        "let now = std::time::SystemTime::now()",
+        ".duration_since(std::time::UNIX_EPOCH)?",
+        ".as_secs() as i64;",
         "let state: multisig_lite::State = program.account(state_pda)?;",
-        "let remaining_accounts: Vec<_> = state",
-        ".queue",
-        ".into_iter()",
-        ".map(|pubkey| AccountMeta {",
-        "pubkey,",
-        "is_signer: false,",
-        "is_writable: true,",
-        "})",
-        ".collect();",
+        "let mut remaining_accounts = vec![];",
+        "for transfer_pubkey in state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "if transfer.expire_at.map(|expire_at| expire_at <= now) == Some(true) {",
+        "remaining_accounts.push(AccountMeta::new(transfer_pubkey, false));",
+        "}",
+        "}",
         "",
-        "// close the multisig account.",
+        "// Prunes the expired transfers.",
         "let sig = program",
         ".request()",
-        ".accounts(multisig_lite::accounts::Close {",
-        "funder: funder.pubkey(),",
+        ".accounts(multisig_lite::accounts::PruneExpired {",
         "state: state_pda,",
         "fund: fund_pda,",
         "})",
-        ".args(multisig_lite::instruction::Close {",
-        "_state_bump: state_bump,",
-        "fund_bump,",
-        "})",
-        ".signer(funder.as_ref())",
+        ".args(multisig_lite::instruction::PruneExpired { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(payer.as_ref())",
         ".send()?;",
         "",
         "println!(\"{sig}\");",
@@ -1518,219 +1458,8710 @@ export const IDL: MultisigLite = {
       ],
       "accounts": [
         {
-          "name": "funder",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "An original funder of the multisig account."
-          ]
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "revoke",
+      "docs": [
+        "Revokes the approval of the queued transfer.",
+        "",
+        "The approval can be revoked only before the transfer",
+        "execution.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to revoke the approval of the pending transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Revokes the approval of the first pending transfer.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Revoke {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "transfer: state.queue[0],",
+        "})",
+        ".args(multisig_lite::instruction::Revoke {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A signer to revoke the approval."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued transfer account to revoke the approval."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSpendingLimit",
+      "docs": [
+        "Creates a spending limit of the signer.",
+        "",
+        "The signer can transfer the lamports from the multisig fund",
+        "up to the limit per day or week with [`multisig_lite::spend`],",
+        "without queuing the transfer.  It requires the m-of-n quorum,",
+        "passed as the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to allow a signer to spend 1 SOL per day on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let spender = Pubkey::new_unique(); // one of the multisig signers.",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), spender.as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Creates a spending limit.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateSpendingLimit {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "limit: limit_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateSpendingLimit {",
+        "pubkey: spender,",
+        "lamports: 1_000_000_000,",
+        "period: multisig_lite::Period::Day,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the rent for the spending limit account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account of the signer."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a spending limit account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "Removes the spending limit of the signer.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts.  The rent of the spending limit account goes back to",
+        "the signer.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to remove the spending limit on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let spender = Pubkey::new_unique(); // one of the multisig signers.",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), spender.as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Removes the spending limit.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::RemoveSpendingLimit {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "limit: limit_pda,",
+        "})",
+        ".args(multisig_lite::instruction::RemoveSpendingLimit {})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It gets back the rent of the spending limit account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account to remove."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "spend",
+      "docs": [
+        "Spends the lamports within the spending limit of the signer.",
+        "",
+        "The lamports are transferred from the multisig fund to the",
+        "recipient right away, without queuing the transfer.  The spent",
+        "lamports are reset at the beginning of every period.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to spend 0.1 SOL within the spending limit on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), signer.pubkey().as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Spends the lamports.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Spend {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "limit: limit_pda,",
+        "recipient: Pubkey::new_unique(),",
+        "})",
+        ".args(multisig_lite::instruction::Spend {",
+        "lamports: 100_000_000,",
+        "fund_bump,",
+        "})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "A signer allowed to spend by the spending limit."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account of the signer."
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient of the lamports.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createBatch",
+      "docs": [
+        "Creates a queued batch of the native SOL payments.",
+        "",
+        "The payments are approved as one unit by [`multisig_lite::approve_batch`]",
+        "and executed by [`multisig_lite::execute_batch`], possibly across",
+        "multiple transactions.  The total lamports of the payments are",
+        "reserved from the multisig fund balance, and the batch account",
+        "creation fee will be given back to the creator from the multisig fund.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to create a batch of the payroll payments on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::{keypair::Keypair, Signer};",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Temporary batch keypair.",
+        "let batch = Keypair::new();",
+        "",
+        "// Pays 1 SOL to each of the 24 recipients.",
+        "let payments = (0..24)",
+        ".map(|_| multisig_lite::Payment {",
+        "recipient: Pubkey::new_unique(),",
+        "lamports: LAMPORTS_PER_SOL,",
+        "})",
+        ".collect();",
+        "",
+        "// Creates a pending batch.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateBatch {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch: batch.pubkey(),",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateBatch { payments, fund_bump })",
+        ".signer(funder.as_ref())",
+        ".signer(&batch)",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the batch.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A batch account to keep the queued payments."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a batch account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "payments",
+          "type": {
+            "vec": {
+              "defined": "Payment"
+            }
+          }
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelBatch",
+      "docs": [
+        "Cancels a queued batch.",
+        "",
+        "It gives the lamports of the payments not executed yet back to",
+        "the multisig balance and closes the batch account.  The creator",
+        "of the batch can cancel it by itself.  Otherwise, it requires",
+        "the m-of-n quorum, passed as the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to cancel the first pending batch on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let creator = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the first pending batch.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let batch = state",
+        ".queue",
+        ".into_iter()",
+        ".find(|pubkey| program.account::<multisig_lite::Batch>(*pubkey).is_ok())",
+        ".ok_or(\"no pending batch\")?;",
+        "",
+        "// Cancels the batch.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CancelBatch {",
+        "signer: creator.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch,",
+        "})",
+        ".args(multisig_lite::instruction::CancelBatch { fund_bump })",
+        ".signer(creator.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A canceler of the batch.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveBatch",
+      "docs": [
+        "Approves the queued batch.",
+        "",
+        "It only records the approval of the signer.  The batch is",
+        "executed by [`multisig_lite::execute_batch`] once it meets",
+        "the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to approve pending batches on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending batches one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for batch_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Batch>(batch_pubkey).is_err() {",
+        "continue; // Skips the transfers and the proposals.",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ApproveBatch {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "batch: batch_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ApproveBatch {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An approver of the batch."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to approve."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeBatch",
+      "docs": [
+        "Executes the approved batch.",
+        "",
+        "It pays the next payments of the batch to the recipients passed",
+        "as the remaining accounts, in the order of the payments.  The",
+        "batch can be executed across multiple transactions, by passing",
+        "the subset of the recipients each time.  The batch account is",
+        "closed once all the payments are executed.  The signer with the",
+        "[`Role::EXECUTOR`] role can execute the batch once it meets the",
+        "m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved batches, ten payments per",
+        "transaction, on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the pending batches one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for batch_pubkey in state.queue {",
+        "let batch: multisig_lite::Batch = match program.account(batch_pubkey) {",
+        "Ok(batch) => batch,",
+        "Err(_) => continue, // Skips the transfers and the proposals.",
+        "};",
+        "",
+        "// Executes the remaining payments in chunks.",
+        "let payments = &batch.payments[batch.executed as usize..];",
+        "for chunk in payments.chunks(10) {",
+        "let remaining_accounts: Vec<_> = chunk",
+        ".iter()",
+        ".map(|payment| AccountMeta::new(payment.recipient, false))",
+        ".collect();",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ExecuteBatch {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch: batch_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ExecuteBatch { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to execute."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "docs": [
+        "Creates a queued proposal of the arbitrary instruction.",
+        "",
+        "The instruction will be executed by [`multisig_lite::execute_proposal`]",
+        "with the multisig fund PDA signature, once it meets the m number of",
+        "signers approval.  Proposal account creation fee will be given back",
+        "to the creator of the proposal from the multisig fund.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to propose a memo instruction signed by the multisig fund",
+        "on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "use std::str::FromStr;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::{keypair::Keypair, Signer};",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Temporary proposal keypair.",
+        "let proposal = Keypair::new();",
+        "",
+        "// The memo instruction signed by the multisig fund PDA.",
+        "let memo = Pubkey::from_str(\"MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr\")?;",
+        "let keys = vec![multisig_lite::ProposalAccount {",
+        "pubkey: fund_pda,",
+        "is_signer: true,",
+        "is_writable: false,",
+        "}];",
+        "",
+        "// Creates a pending proposal.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateProposal {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal: proposal.pubkey(),",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateProposal {",
+        "program: memo,",
+        "keys,",
+        "data: b\"Hello from the multisig\".to_vec(),",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".signer(&proposal)",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the proposal.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A proposal account to keep the queued instruction."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a proposal account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "program",
+          "type": "publicKey"
+        },
+        {
+          "name": "keys",
+          "type": {
+            "vec": {
+              "defined": "ProposalAccount"
+            }
+          }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelProposal",
+      "docs": [
+        "Cancels a queued proposal.",
+        "",
+        "It closes the proposal account without executing the proposal",
+        "instruction.  The creator of the proposal can cancel it by",
+        "itself.  Otherwise, it requires the m-of-n quorum, passed as",
+        "the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to cancel the first pending proposal on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let creator = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the first pending proposal.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let proposal = state",
+        ".queue",
+        ".into_iter()",
+        ".find(|pubkey| program.account::<multisig_lite::Proposal>(*pubkey).is_ok())",
+        ".ok_or(\"no pending proposal\")?;",
+        "",
+        "// Cancels the proposal.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CancelProposal {",
+        "signer: creator.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal,",
+        "})",
+        ".args(multisig_lite::instruction::CancelProposal { fund_bump })",
+        ".signer(creator.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A canceler of the proposal.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveProposal",
+      "docs": [
+        "Approves the queued proposal.",
+        "",
+        "It only records the approval of the signer.  The proposal",
+        "is executed by [`multisig_lite::execute_proposal`] once it",
+        "meets the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to approve pending proposals on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending proposals one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for proposal_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Proposal>(proposal_pubkey).is_err() {",
+        "continue; // Skips the transfers.",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ApproveProposal {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "proposal: proposal_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ApproveProposal {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An approver of the proposal."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to approve."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "docs": [
+        "Executes the approved proposal.",
+        "",
+        "It executes the proposal instruction with the multisig fund PDA",
+        "signature and closes the proposal account.  The signer with the",
+        "[`Role::EXECUTOR`] role can execute the proposal once it meets",
+        "the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved proposals on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the pending proposals one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for proposal_pubkey in state.queue {",
+        "let proposal: multisig_lite::Proposal = match program.account(proposal_pubkey) {",
+        "Ok(proposal) => proposal,",
+        "Err(_) => continue, // Skips the transfers.",
+        "};",
+        "",
+        "// The proposal instruction accounts, except the multisig fund PDA,",
+        "// and the program to execute.",
+        "let mut remaining_accounts: Vec<_> = proposal",
+        ".keys",
+        ".iter()",
+        ".filter(|key| key.pubkey != fund_pda)",
+        ".map(|key| AccountMeta {",
+        "pubkey: key.pubkey,",
+        "is_signer: key.is_signer,",
+        "is_writable: key.is_writable,",
+        "})",
+        ".collect();",
+        "remaining_accounts.push(AccountMeta::new_readonly(proposal.program, false));",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ExecuteProposal {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal: proposal_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ExecuteProposal { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account, which signs the proposal instruction.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to execute."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addSigner",
+      "docs": [
+        "Adds a new signer to the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The new signer votes with the weight of one.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to add a new signer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Adds a new signer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::AddSigner {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::AddSigner {",
+        "pubkey: Pubkey::new_unique(),",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the additional rent for the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to pay the additional rent."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeSigner",
+      "docs": [
+        "Removes the signer from the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The threshold should be lowered in advance in case it's",
+        "higher than the summed weight of the remaining signers.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to remove the signer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Removes the signer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::RemoveSigner {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::RemoveSigner {",
+        "pubkey: Pubkey::new_unique(),",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It receives the refunded rent of the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to realloc the state account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "changeThreshold",
+      "docs": [
+        "Changes the threshold of the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, with the current threshold.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to change the threshold on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Changes the threshold to 2.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ChangeThreshold {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "})",
+        ".args(multisig_lite::instruction::ChangeThreshold { m: 2 })",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "m",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setRoles",
+      "docs": [
+        "Sets the roles of the signer.",
+        "",
+        "The [`Role`] flags limit what the signer can do, e.g. an",
+        "accounting bot may propose the transfers but not approve them.",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The number of the approvers should not go below the threshold.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to make a signer a proposer only on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Makes the bot a proposer only.",
+        "let bot = Pubkey::new_unique(); // one of the multisig signers.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::SetRoles {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "})",
+        ".args(multisig_lite::instruction::SetRoles {",
+        "pubkey: bot,",
+        "roles: multisig_lite::Role::PROPOSER,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        },
+        {
+          "name": "roles",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resizeQueue",
+      "docs": [
+        "Resizes the transfer queue of the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts.  The signer pays the additional rent for the larger",
+        "queue, or gets back the rent for the smaller one.  The queue",
+        "can't be shrunk below the number of the pending transactions.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to resize the transfer queue on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Grows the transfer queue to 20.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ResizeQueue {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::ResizeQueue { q: 20 })",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the additional rent, or receives the refunded rent,",
+            "of the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to realloc the state account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "q",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "close",
+      "docs": [
+        "Closes the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts together with the queued transfer accounts.",
+        "All the queued accounts should be passed in and writable, and",
+        "any other remaining account is rejected unless it's a co-signer.",
+        "",
+        "It cleans up all the remaining accounts and sends the remaining",
+        "fund to the destination.  The rent of the state account goes",
+        "back to the funder, original creator of the multisig account.",
+        "The tokens held by the multisig fund token accounts are not",
+        "swept, so please transfer those out with the token transfers",
+        "before closing the multisig account.  The spending limit",
+        "accounts should be removed by [`multisig_lite::remove_spending_limit`]",
+        "as well.",
+        "",
+        "# Examples",
+        "",
+        "Here is how you close the multisig account on devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let mut remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Gets the remaining transfers to collects the rents.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "remaining_accounts.extend(state.queue.into_iter().map(|pubkey| AccountMeta {",
+        "pubkey,",
+        "is_signer: false,",
+        "is_writable: true,",
+        "}));",
+        "",
+        "// close the multisig account.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Close {",
+        "signer: funder.pubkey(),",
+        "funder: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "destination: funder.pubkey(),",
+        "})",
+        ".args(multisig_lite::instruction::Close {",
+        "_index: index,",
+        "_state_bump: state_bump,",
+        "fund_bump,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(funder.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "An original funder of the multisig account.",
+            "",
+            "It gets back the rent of the multisig state account.",
+            ""
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A destination of the remaining fund.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u64"
+        },
+        {
+          "name": "stateBump",
+          "type": "u8"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "state",
+      "docs": [
+        "A multisig [`State`] PDA account data.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`State`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Print out the state account.",
+        "println!(\"{state:?}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "m",
+            "docs": [
+              "A threshold, in the summed [`State::weights`] of the approvals."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "An array of signers Pubkey, in the order given at the creation."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "roles",
+            "docs": [
+              "An array of the signer [`Role`] flags.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the [`State::signers`] array."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "weights",
+            "docs": [
+              "An array of the signer voting weights.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the [`State::signers`] array."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "fund",
+            "docs": [
+              "A fund PDA account, holding the native SOL."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "A balance of the fund in lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pending",
+            "docs": [
+              "A total lamports reserved by the pending transactions."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "transfers",
+            "docs": [
+              "A number of the transfers created so far.",
+              "",
+              "It's used as the index of the next [`Transfer`] PDA account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "q",
+            "docs": [
+              "A limit of the pending transactions."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "An array of the pending transactions."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "pendingTokens",
+            "docs": [
+              "An array of the token amounts reserved by the pending token",
+              "transfers, one for each mint."
+            ],
+            "type": {
+              "vec": {
+                "defined": "PendingToken"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "transfer",
+      "docs": [
+        "A multisig [`Transfer`] account data.",
+        "",
+        "The account is a PDA of the multisig state and the index,",
+        "which is the [`State::transfers`] at the creation.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Transfer`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued transfers.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Transfer` accounts iteratively.",
+        "for transfer in state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(transfer) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "println!(\"{transfer:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the transfer, one of the multisig",
+              "signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "A recipient of the transfer."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A lamports to transfer.",
+              "",
+              "It's the token amount in case of the token transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "A token mint of the transfer.",
+              "",
+              "It's `None` for the native SOL transfer."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "executeAfter",
+            "docs": [
+              "An earliest execution time of the transfer, in unix timestamp.",
+              "",
+              "The approved transfer stays in the queue until then."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "expireAt",
+            "docs": [
+              "An expiration time of the transfer, in unix timestamp.",
+              "",
+              "The expired transfer can't be approved nor executed, and",
+              "will be pruned by [`multisig_lite::prune_expired`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "memo",
+            "docs": [
+              "A memo, e.g. the invoice reference, of the transfer.",
+              "",
+              "It's recorded through the SPL Memo program at the execution."
+            ],
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the transfer.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "batch",
+      "docs": [
+        "A multisig [`Batch`] account data.",
+        "",
+        "It keeps the list of the native SOL payments, approved as one",
+        "unit by the m number of signers and executed across one or more",
+        "transactions by [`multisig_lite::execute_batch`].",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Batch`] accounts on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued batches.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Batch` accounts iteratively.",
+        "for batch in state.queue {",
+        "let batch: multisig_lite::Batch = match program.account(batch) {",
+        "Ok(batch) => batch,",
+        "Err(_) => continue, // Skips the transfers and the proposals.",
+        "};",
+        "println!(\"{batch:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the batch, one of the multisig signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payments",
+            "docs": [
+              "Payments of the batch."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Payment"
+              }
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "A number of the payments already executed.",
+              "",
+              "The payments are executed in order, starting from this index."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the batch.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "docs": [
+        "A multisig [`Proposal`] account data.",
+        "",
+        "It keeps an arbitrary instruction, which will be executed",
+        "with the multisig fund PDA signature once it meets the",
+        "m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Proposal`] accounts on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued proposals.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Proposal` accounts iteratively.",
+        "for proposal in state.queue {",
+        "let proposal: multisig_lite::Proposal = match program.account(proposal) {",
+        "Ok(proposal) => proposal,",
+        "Err(_) => continue, // Skips the transfers.",
+        "};",
+        "println!(\"{proposal:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the proposal, one of the multisig",
+              "signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "program",
+            "docs": [
+              "A program to execute the instruction."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "docs": [
+              "Accounts of the instruction."
+            ],
+            "type": {
+              "vec": {
+                "defined": "ProposalAccount"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Data of the instruction."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the proposal.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "A multisig [`SpendingLimit`] account data.",
+        "",
+        "It allows the signer to transfer the lamports from the multisig",
+        "fund up to the limit per period, without the m-of-n approval.",
+        "The account is a PDA of the multisig state and the signer, and",
+        "created and removed only by the m-of-n quorum.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`SpendingLimit`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), signer.pubkey().as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::SpendingLimit` account.",
+        "let limit: multisig_lite::SpendingLimit = program.account(limit_pda)?;",
+        "",
+        "println!(\"{limit:?}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "state",
+            "docs": [
+              "A multisig state PDA account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "signer",
+            "docs": [
+              "A signer allowed to spend."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A maximum lamports to spend per period."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "docs": [
+              "A period of the spending limit."
+            ],
+            "type": {
+              "defined": "Period"
+            }
+          },
+          {
+            "name": "spent",
+            "docs": [
+              "Lamports spent in the current period."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodStart",
+            "docs": [
+              "A start of the current period, in unix timestamp."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "PendingToken",
+      "docs": [
+        "A token amount reserved by the pending token transfers of the mint.",
+        "",
+        "It's kept in [`State::pending_tokens`], so that the queued token",
+        "transfers don't exceed the multisig fund token balance."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "A token mint."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "A token amount reserved by the pending token transfers."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Payment",
+      "docs": [
+        "A payment of the [`Batch`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "A recipient of the payment."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A lamports to pay."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalAccount",
+      "docs": [
+        "An account of the [`Proposal`] instruction.",
+        "",
+        "It mirrors [`AccountMeta`], which is not serializable by Anchor."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "docs": [
+              "A public key of the account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "docs": [
+              "True if the instruction requires the signature of the account."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "docs": [
+              "True if the account data or the lamports may be mutated."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Period",
+      "docs": [
+        "A period of the [`SpendingLimit`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Day"
+          },
+          {
+            "name": "Week"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "m",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "weights",
+          "type": "bytes",
+          "index": false
+        },
+        {
+          "name": "q",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Funded",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "balance",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BalanceSynced",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "balance",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pending",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferPruned",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Approved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Revoked",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Spent",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "spent",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payments",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchApproved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "executed",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "program",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalApproved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "program",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SignerAdded",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SignerRemoved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "m",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RolesChanged",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roles",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "QueueResized",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "q",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Closed",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "AccountEmpty",
+      "msg": "Multisig account is empty. Please create transactions"
+    },
+    {
+      "code": 6001,
+      "name": "AccountFull",
+      "msg": "Multisig transaction queue is full. Please approve those."
+    },
+    {
+      "code": 6002,
+      "name": "AccountLocked",
+      "msg": "Multisig account is locked. Please approve the transactions"
+    },
+    {
+      "code": 6003,
+      "name": "MissingRecipientAccountInfo",
+      "msg": "Missing transfer recipient AccountInfo"
+    },
+    {
+      "code": 6004,
+      "name": "FundAccountNotWritable",
+      "msg": "Fund account is not writable"
+    },
+    {
+      "code": 6005,
+      "name": "FundAccountIsNotEmpty",
+      "msg": "Fund account data is not empty"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidFundAddress",
+      "msg": "Invalid fund account"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidFundBumpSeed",
+      "msg": "Invalid fund bump seed"
+    },
+    {
+      "code": 6008,
+      "name": "NoSigners",
+      "msg": "No signers provided"
+    },
+    {
+      "code": 6009,
+      "name": "TooManySigners",
+      "msg": "Too many signers provided"
+    },
+    {
+      "code": 6010,
+      "name": "ThresholdTooHigh",
+      "msg": "Threshold too high"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidSigner",
+      "msg": "Invalid signer"
+    },
+    {
+      "code": 6012,
+      "name": "NotEnoughFundBalance",
+      "msg": "There is not enough fund balance"
+    },
+    {
+      "code": 6013,
+      "name": "TransferNotQueued",
+      "msg": "Transfer is not queued in the multisig account"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidRecipient",
+      "msg": "Invalid transfer recipient"
+    },
+    {
+      "code": 6015,
+      "name": "ThresholdNotMet",
+      "msg": "Threshold not met"
+    },
+    {
+      "code": 6016,
+      "name": "NotApproved",
+      "msg": "Transfer is not approved by the signer"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidMint",
+      "msg": "Invalid token mint for the transfer"
+    },
+    {
+      "code": 6018,
+      "name": "ProposalNotQueued",
+      "msg": "Proposal is not queued in the multisig account"
+    },
+    {
+      "code": 6019,
+      "name": "TransferExpired",
+      "msg": "Transfer had been expired"
+    },
+    {
+      "code": 6020,
+      "name": "TransferNotExpired",
+      "msg": "Transfer is not expired yet"
+    },
+    {
+      "code": 6021,
+      "name": "TransferTimeLocked",
+      "msg": "Transfer is time-locked"
+    },
+    {
+      "code": 6022,
+      "name": "SpendingLimitExceeded",
+      "msg": "Spending limit is exceeded"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidSpendingLimit",
+      "msg": "Invalid spending limit account"
+    },
+    {
+      "code": 6024,
+      "name": "NotProposer",
+      "msg": "Signer is not a proposer"
+    },
+    {
+      "code": 6025,
+      "name": "NotApprover",
+      "msg": "Signer is not an approver"
+    },
+    {
+      "code": 6026,
+      "name": "NotExecutor",
+      "msg": "Signer is not an executor"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidWeights",
+      "msg": "Invalid signer weights"
+    },
+    {
+      "code": 6028,
+      "name": "DuplicateSigner",
+      "msg": "Duplicate signer"
+    },
+    {
+      "code": 6029,
+      "name": "QueueTooShort",
+      "msg": "Queue is too short"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPayments",
+      "msg": "Invalid batch payments"
+    },
+    {
+      "code": 6031,
+      "name": "BatchNotQueued",
+      "msg": "Batch is not queued"
+    },
+    {
+      "code": 6032,
+      "name": "MemoTooLong",
+      "msg": "Memo is too long"
+    },
+    {
+      "code": 6033,
+      "name": "UnexpectedAccount",
+      "msg": "Unexpected remaining account"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidQueuedAccount",
+      "msg": "Invalid queued account"
+    },
+    {
+      "code": 6035,
+      "name": "LamportsOverflow",
+      "msg": "Lamports overflow"
+    },
+    {
+      "code": 6036,
+      "name": "LamportsUnderflow",
+      "msg": "Lamports underflow"
+    },
+    {
+      "code": 6037,
+      "name": "FundBelowReserve",
+      "msg": "Fund is below the reserve"
+    },
+    {
+      "code": 6038,
+      "name": "MissingQueuedAccount",
+      "msg": "Missing queued account"
+    }
+  ],
+  "metadata": {
+    "address": "Ecycmji8eeggXrA3rD2cdEHpHDnP4btvVfcyTBS9cG9t"
+  }
+};
+
+export const IDL: MultisigLite = {
+  "version": "0.0.8",
+  "name": "multisig_lite",
+  "docs": [
+    "Module representing the program instruction handlers.",
+    "",
+    "# Examples",
+    "",
+    "Here is how to approve pending transfers on Devnet.",
+    "",
+    "Please take a look at the individual functions for other",
+    "instruction opperations. e.g. [`multisig_lite::fund`] for",
+    "how to fund the multisig account.",
+    "",
+    "```no_run",
+    "use std::rc::Rc;",
+    "",
+    "use solana_sdk::commitment_config::CommitmentConfig;",
+    "use solana_sdk::pubkey::Pubkey;",
+    "use solana_sdk::signature::read_keypair_file;",
+    "use solana_sdk::signer::Signer;",
+    "",
+    "use anchor_client::{Client, Cluster};",
+    "",
+    "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+    "let url = Cluster::Devnet;",
+    "let signer = Rc::new(read_keypair_file(",
+    "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+    ")?);",
+    "let opts = CommitmentConfig::processed();",
+    "let pid = multisig_lite::id();",
+    "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+    "",
+    "// Gets the PDAs.",
+    "let index = 0u64; // the first multisig account of the funder.",
+    "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+    "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+    "&pid,",
+    ");",
+    "",
+    "// Approves the pending transfers one by one.",
+    "let state: multisig_lite::State = program.account(state_pda)?;",
+    "for transfer_pubkey in state.queue {",
+    "if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {",
+    "continue; // Skips the proposals.",
+    "}",
+    "",
+    "let sig = program",
+    ".request()",
+    ".accounts(multisig_lite::accounts::Approve {",
+    "signer: signer.pubkey(),",
+    "state: state_pda,",
+    "transfer: transfer_pubkey,",
+    "})",
+    ".args(multisig_lite::instruction::Approve {})",
+    ".signer(signer.as_ref())",
+    ".send()?;",
+    "",
+    "println!(\"{sig}\");",
+    "}",
+    "# Ok(())",
+    "# }",
+    "```"
+  ],
+  "instructions": [
+    {
+      "name": "create",
+      "docs": [
+        "Creates a multisig account.",
+        "",
+        "The funder can create multiple multisig accounts with the",
+        "different index, as both the funder Pubkey and the index",
+        "are used for the multisig PDA address generation.  Other",
+        "instructions, except [`multisig_lite::fund`] and",
+        "[`multisig_lite::close`], locate the multisig account by",
+        "the address.",
+        "",
+        "Each signer votes with the weight given at the same position",
+        "in the `weights` array, and the transfers and proposals are",
+        "approved once the summed weight of the approvals reaches the",
+        "threshold, `m`.  The threshold should be reachable by the",
+        "signers.",
+        "",
+        "The signers are kept in the given order, and the duplicate",
+        "signers are rejected.",
+        "",
+        "# Examples",
+        "",
+        "Here is how you create a multisig account on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Creates a multisig account.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Create {",
+        "funder: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::Create {",
+        "m: 2, // m as in m/n.",
+        "signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],",
+        "weights: vec![2, 1, 1], // the funder's vote counts twice.",
+        "q: 10, // transfer queue limit.",
+        "_index: index,",
+        "_state_bump: state_bump,",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A funder of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a multisig PDA accounts."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "m",
+          "type": "u8"
+        },
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "weights",
+          "type": "bytes"
+        },
+        {
+          "name": "q",
+          "type": "u8"
+        },
+        {
+          "name": "index",
+          "type": "u64"
+        },
+        {
+          "name": "stateBump",
+          "type": "u8"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "fund",
+      "docs": [
+        "Funds lamports to the multisig fund account.",
+        "",
+        "The funding is only allowed by the multisig account funder.",
+        "Other accounts can deposit through [`multisig_lite::deposit`].",
+        "",
+        "# Examples",
+        "",
+        "Here is how to fund to the multisig account on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Funds the multisig account.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Fund {",
+        "funder: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::Fund {",
+        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
+        "_index: index,",
+        "_state_bump: state_bump,",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A funder of the account.",
+            "",
+            "The funding is only allowed by the multisig account creator.",
+            "Please use [`multisig_lite::deposit`] for other payers."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to make the transfer of the fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "index",
+          "type": "u64"
+        },
+        {
+          "name": "stateBump",
+          "type": "u8"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "docs": [
+        "Deposits lamports to the multisig fund account.",
+        "",
+        "Unlike [`multisig_lite::fund`], any payer can deposit lamports",
+        "to the multisig account, located by the address.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to deposit to the multisig account on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let payer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
+        "",
+        "// The multisig account to deposit, shared by the multisig signers.",
+        "let state_pda = Pubkey::new_unique();",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Deposits to the multisig account.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Deposit {",
+        "payer: payer.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::Deposit {",
+        "lamports: 10 * LAMPORTS_PER_SOL,",
+        "fund_bump,",
+        "})",
+        ".signer(payer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A payer of the deposit.",
+            "",
+            "Any account can deposit to the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to make the transfer of the fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "syncBalance",
+      "docs": [
+        "Synchronizes the multisig balance with the fund account lamports.",
+        "",
+        "The lamports directly transferred to the fund account, e.g.",
+        "by the system program transfer, are not accounted in the",
+        "multisig balance.  This permissionless instruction recomputes",
+        "the balance as the fund account lamports minus the rent-exempt",
+        "minimum and the lamports reserved by the pending transfers.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to synchronize the multisig balance on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let payer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
+        "",
+        "// The multisig account to synchronize.",
+        "let state_pda = Pubkey::new_unique();",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Synchronizes the multisig balance.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::SyncBalance {",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "})",
+        ".args(multisig_lite::instruction::SyncBalance { fund_bump })",
+        ".signer(payer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createTransfer",
+      "docs": [
+        "Creates a queued transfer lamports to the recipient.",
+        "",
+        "The transfer account is a PDA of the multisig state and the",
+        "[`State::transfers`] index, and its creation fee will be given",
+        "back to the creator of the transfer from the multisig fund.",
+        "The [`State::balance`] should cover both the transfer lamports",
+        "and the creation fee, which goes back to the balance once the",
+        "transfer account is closed.",
+        "The optional `execute_after` unix timestamp time-locks the",
+        "transfer execution, so that the signers can notice and",
+        "cancel the suspicious transfer.  The optional `expire_at`",
+        "unix timestamp voids the transfer after the deadline.",
+        "The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, tells",
+        "what the transfer is for and is recorded through the SPL Memo",
+        "program at the execution.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to create a pending transfer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the next transfer PDA.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(",
+        "&[b\"transfer\", state_pda.as_ref(), &state.transfers.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Creates a pending transfer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateTransfer {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "transfer: transfer_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateTransfer {",
+        "recipient: Pubkey::new_unique(),",
+        "lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)",
+        "execute_after: None,",
+        "expire_at: None,",
+        "memo: Some(\"Invoice #1234\".to_string()),",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the fund transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A transfer PDA account to keep the queued transfer info."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a transfer account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "executeAfter",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "expireAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createTokenTransfer",
+      "docs": [
+        "Creates a queued token transfer to the recipient.",
+        "",
+        "The tokens are transferred from the multisig fund associated",
+        "token account of the mint, which should be created before",
+        "through the associated token account program.  The token",
+        "balance should cover the amount on top of the other pending",
+        "token transfers of the mint, kept in [`State::pending_tokens`].",
+        "The transfer account is a PDA of the multisig state and the",
+        "[`State::transfers`] index, and its creation fee will be given",
+        "back to the creator of the transfer from the multisig fund.",
+        "The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, is",
+        "recorded through the SPL Memo program at the execution, as",
+        "[`multisig_lite::create_transfer`] does.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to create a pending token transfer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "use anchor_spl::associated_token::get_associated_token_address;",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// The token mint and the fund associated token account.",
+        "let mint = Pubkey::new_unique();",
+        "let fund_token = get_associated_token_address(&fund_pda, &mint);",
+        "",
+        "// Gets the next transfer PDA.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(",
+        "&[b\"transfer\", state_pda.as_ref(), &state.transfers.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Creates a pending token transfer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateTokenTransfer {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "mint,",
+        "fund_token,",
+        "transfer: transfer_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateTokenTransfer {",
+        "recipient: Pubkey::new_unique(),",
+        "amount: 1_000,",
+        "execute_after: None,",
+        "expire_at: None,",
+        "memo: Some(\"Invoice #1234\".to_string()),",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the token transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A token mint of the transfer."
+          ]
+        },
+        {
+          "name": "fundToken",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund associated token account of the mint."
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A transfer PDA account to keep the queued transfer info."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a transfer account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "executeAfter",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "expireAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelTransfer",
+      "docs": [
+        "Cancels a queued transfer.",
+        "",
+        "It gives the transfer lamports back to the multisig balance",
+        "and closes the transfer account.  The creator of the transfer",
+        "can cancel it by itself.  Otherwise, it requires the m-of-n",
+        "quorum, passed as the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to cancel the first pending transfer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let creator = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the first pending transfer.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let transfer = state.queue[0];",
+        "",
+        "// Cancels the transfer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CancelTransfer {",
+        "signer: creator.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "transfer,",
+        "})",
+        ".args(multisig_lite::instruction::CancelTransfer { fund_bump })",
+        ".signer(creator.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A canceler of the transfer.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued transfer account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approve",
+      "docs": [
+        "Approves the queued transfer.",
+        "",
+        "It only records the approval of the signer.  The transfer is",
+        "executed by [`multisig_lite::execute`], or by",
+        "[`multisig_lite::execute_token`] for the token transfer,",
+        "once it meets the m number of signers approval.",
+        "The expired transfer can't be approved and will be pruned",
+        "by [`multisig_lite::prune_expired`].",
+        "",
+        "Approving the same transfer again is a no-op.  The transfer",
+        "not in the multisig queue is rejected with",
+        "[`Error::TransferNotQueued`], instead of being silently skipped.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to approve pending transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending transfers one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for transfer_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {",
+        "continue; // Skips the proposals.",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Approve {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "transfer: transfer_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::Approve {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An approver of the transfer."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued transfer account to approve."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "execute",
+      "docs": [
+        "Executes the approved transfer.",
+        "",
+        "The signer with the [`Role::EXECUTOR`] role can execute the",
+        "transfer once it meets the m number of signers approval, which",
+        "makes it possible to batch the execution by an executor bot.",
+        "The time-locked transfer can't be executed until the",
+        "`execute_after` timestamp.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the approved transfers one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for transfer_pubkey in &state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "let approvals: usize = transfer",
+        ".signed",
+        ".iter()",
+        ".zip(&state.weights)",
+        ".filter(|(signed, _)| **signed)",
+        ".map(|(_, weight)| *weight as usize)",
+        ".sum();",
+        "if transfer.mint.is_some() || approvals < state.m as usize {",
+        "continue;",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Execute {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "transfer: *transfer_pubkey,",
+        "recipient: transfer.recipient,",
+        "memo_program: multisig_lite::memo::ID,",
+        "})",
+        ".args(multisig_lite::instruction::Execute { fund_bump })",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued transfer account to execute.",
+            "",
+            "The token transfer is executed by [`multisig_lite::execute_token`].",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient of the transfer.",
+            ""
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The SPL Memo program to record the transfer memo."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "executeToken",
+      "docs": [
+        "Executes the approved token transfer.",
+        "",
+        "The tokens are transferred from the multisig fund associated",
+        "token account to the recipient token account, signed by the",
+        "multisig fund PDA.  The signer with the [`Role::EXECUTOR`] role",
+        "can execute the token transfer once it meets the m number of",
+        "signers approval.  The memo of the transfer, if any, is recorded",
+        "through the SPL Memo program.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved token transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "use anchor_spl::associated_token::get_associated_token_address;",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the approved token transfers one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for transfer_pubkey in &state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "let mint = match transfer.mint {",
+        "Some(mint) => mint,",
+        "None => continue,",
+        "};",
+        "let approvals: usize = transfer",
+        ".signed",
+        ".iter()",
+        ".zip(&state.weights)",
+        ".filter(|(signed, _)| **signed)",
+        ".map(|(_, weight)| *weight as usize)",
+        ".sum();",
+        "if approvals < state.m as usize {",
+        "continue;",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ExecuteToken {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "transfer: *transfer_pubkey,",
+        "mint,",
+        "fund_token: get_associated_token_address(&fund_pda, &mint),",
+        "recipient_token: get_associated_token_address(&transfer.recipient, &mint),",
+        "token_program: anchor_spl::token::ID,",
+        "memo_program: multisig_lite::memo::ID,",
+        "})",
+        ".args(multisig_lite::instruction::ExecuteToken { fund_bump })",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued token transfer account to execute.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A token mint of the transfer."
+          ]
+        },
+        {
+          "name": "fundToken",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund associated token account of the mint."
+          ]
+        },
+        {
+          "name": "recipientToken",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient token account of the transfer."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to make the token transfer."
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The SPL Memo program to record the transfer memo."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "pruneExpired",
+      "docs": [
+        "Prunes the expired transfers.",
+        "",
+        "Anyone can prune the expired transfers, passed as the writable",
+        "remaining accounts.  It closes the transfer accounts by giving",
+        "back the rent fee to the multisig fund, and restores the",
+        "reserved lamports to the multisig balance.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to prune the expired transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let payer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, payer.clone(), opts).program(pid);",
+        "",
+        "// The multisig account to prune the expired transfers.",
+        "let state_pda = Pubkey::new_unique();",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the expired transfers.",
+        "let now = std::time::SystemTime::now()",
+        ".duration_since(std::time::UNIX_EPOCH)?",
+        ".as_secs() as i64;",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let mut remaining_accounts = vec![];",
+        "for transfer_pubkey in state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(transfer_pubkey) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "if transfer.expire_at.map(|expire_at| expire_at <= now) == Some(true) {",
+        "remaining_accounts.push(AccountMeta::new(transfer_pubkey, false));",
+        "}",
+        "}",
+        "",
+        "// Prunes the expired transfers.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::PruneExpired {",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "})",
+        ".args(multisig_lite::instruction::PruneExpired { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(payer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "revoke",
+      "docs": [
+        "Revokes the approval of the queued transfer.",
+        "",
+        "The approval can be revoked only before the transfer",
+        "execution.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to revoke the approval of the pending transfers on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Revokes the approval of the first pending transfer.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Revoke {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "transfer: state.queue[0],",
+        "})",
+        ".args(multisig_lite::instruction::Revoke {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A signer to revoke the approval."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "transfer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued transfer account to revoke the approval."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSpendingLimit",
+      "docs": [
+        "Creates a spending limit of the signer.",
+        "",
+        "The signer can transfer the lamports from the multisig fund",
+        "up to the limit per day or week with [`multisig_lite::spend`],",
+        "without queuing the transfer.  It requires the m-of-n quorum,",
+        "passed as the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to allow a signer to spend 1 SOL per day on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let spender = Pubkey::new_unique(); // one of the multisig signers.",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), spender.as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Creates a spending limit.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateSpendingLimit {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "limit: limit_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateSpendingLimit {",
+        "pubkey: spender,",
+        "lamports: 1_000_000_000,",
+        "period: multisig_lite::Period::Day,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the rent for the spending limit account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account of the signer."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a spending limit account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "Removes the spending limit of the signer.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts.  The rent of the spending limit account goes back to",
+        "the signer.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to remove the spending limit on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let spender = Pubkey::new_unique(); // one of the multisig signers.",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), spender.as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Removes the spending limit.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::RemoveSpendingLimit {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "limit: limit_pda,",
+        "})",
+        ".args(multisig_lite::instruction::RemoveSpendingLimit {})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It gets back the rent of the spending limit account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account to remove."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "spend",
+      "docs": [
+        "Spends the lamports within the spending limit of the signer.",
+        "",
+        "The lamports are transferred from the multisig fund to the",
+        "recipient right away, without queuing the transfer.  The spent",
+        "lamports are reset at the beginning of every period.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to spend 0.1 SOL within the spending limit on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), signer.pubkey().as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Spends the lamports.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Spend {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "limit: limit_pda,",
+        "recipient: Pubkey::new_unique(),",
+        "})",
+        ".args(multisig_lite::instruction::Spend {",
+        "lamports: 100_000_000,",
+        "fund_bump,",
+        "})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "A signer allowed to spend by the spending limit."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "limit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A spending limit PDA account of the signer."
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A recipient of the lamports.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lamports",
+          "type": "u64"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createBatch",
+      "docs": [
+        "Creates a queued batch of the native SOL payments.",
+        "",
+        "The payments are approved as one unit by [`multisig_lite::approve_batch`]",
+        "and executed by [`multisig_lite::execute_batch`], possibly across",
+        "multiple transactions.  The total lamports of the payments are",
+        "reserved from the multisig fund balance, and the batch account",
+        "creation fee will be given back to the creator from the multisig fund.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to create a batch of the payroll payments on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::native_token::LAMPORTS_PER_SOL;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::{keypair::Keypair, Signer};",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Temporary batch keypair.",
+        "let batch = Keypair::new();",
+        "",
+        "// Pays 1 SOL to each of the 24 recipients.",
+        "let payments = (0..24)",
+        ".map(|_| multisig_lite::Payment {",
+        "recipient: Pubkey::new_unique(),",
+        "lamports: LAMPORTS_PER_SOL,",
+        "})",
+        ".collect();",
+        "",
+        "// Creates a pending batch.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateBatch {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch: batch.pubkey(),",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateBatch { payments, fund_bump })",
+        ".signer(funder.as_ref())",
+        ".signer(&batch)",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the batch.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A batch account to keep the queued payments."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a batch account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "payments",
+          "type": {
+            "vec": {
+              "defined": "Payment"
+            }
+          }
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelBatch",
+      "docs": [
+        "Cancels a queued batch.",
+        "",
+        "It gives the lamports of the payments not executed yet back to",
+        "the multisig balance and closes the batch account.  The creator",
+        "of the batch can cancel it by itself.  Otherwise, it requires",
+        "the m-of-n quorum, passed as the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to cancel the first pending batch on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let creator = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the first pending batch.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let batch = state",
+        ".queue",
+        ".into_iter()",
+        ".find(|pubkey| program.account::<multisig_lite::Batch>(*pubkey).is_ok())",
+        ".ok_or(\"no pending batch\")?;",
+        "",
+        "// Cancels the batch.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CancelBatch {",
+        "signer: creator.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch,",
+        "})",
+        ".args(multisig_lite::instruction::CancelBatch { fund_bump })",
+        ".signer(creator.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A canceler of the batch.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveBatch",
+      "docs": [
+        "Approves the queued batch.",
+        "",
+        "It only records the approval of the signer.  The batch is",
+        "executed by [`multisig_lite::execute_batch`] once it meets",
+        "the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to approve pending batches on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending batches one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for batch_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Batch>(batch_pubkey).is_err() {",
+        "continue; // Skips the transfers and the proposals.",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ApproveBatch {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "batch: batch_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ApproveBatch {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An approver of the batch."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to approve."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeBatch",
+      "docs": [
+        "Executes the approved batch.",
+        "",
+        "It pays the next payments of the batch to the recipients passed",
+        "as the remaining accounts, in the order of the payments.  The",
+        "batch can be executed across multiple transactions, by passing",
+        "the subset of the recipients each time.  The batch account is",
+        "closed once all the payments are executed.  The signer with the",
+        "[`Role::EXECUTOR`] role can execute the batch once it meets the",
+        "m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved batches, ten payments per",
+        "transaction, on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the pending batches one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for batch_pubkey in state.queue {",
+        "let batch: multisig_lite::Batch = match program.account(batch_pubkey) {",
+        "Ok(batch) => batch,",
+        "Err(_) => continue, // Skips the transfers and the proposals.",
+        "};",
+        "",
+        "// Executes the remaining payments in chunks.",
+        "let payments = &batch.payments[batch.executed as usize..];",
+        "for chunk in payments.chunks(10) {",
+        "let remaining_accounts: Vec<_> = chunk",
+        ".iter()",
+        ".map(|payment| AccountMeta::new(payment.recipient, false))",
+        ".collect();",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ExecuteBatch {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "batch: batch_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ExecuteBatch { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund account.",
+            ""
+          ]
+        },
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued batch account to execute."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "docs": [
+        "Creates a queued proposal of the arbitrary instruction.",
+        "",
+        "The instruction will be executed by [`multisig_lite::execute_proposal`]",
+        "with the multisig fund PDA signature, once it meets the m number of",
+        "signers approval.  Proposal account creation fee will be given back",
+        "to the creator of the proposal from the multisig fund.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to propose a memo instruction signed by the multisig fund",
+        "on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "use std::str::FromStr;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::{keypair::Keypair, Signer};",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Temporary proposal keypair.",
+        "let proposal = Keypair::new();",
+        "",
+        "// The memo instruction signed by the multisig fund PDA.",
+        "let memo = Pubkey::from_str(\"MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr\")?;",
+        "let keys = vec![multisig_lite::ProposalAccount {",
+        "pubkey: fund_pda,",
+        "is_signer: true,",
+        "is_writable: false,",
+        "}];",
+        "",
+        "// Creates a pending proposal.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CreateProposal {",
+        "creator: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal: proposal.pubkey(),",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::CreateProposal {",
+        "program: memo,",
+        "keys,",
+        "data: b\"Hello from the multisig\".to_vec(),",
+        "fund_bump,",
+        "})",
+        ".signer(funder.as_ref())",
+        ".signer(&proposal)",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An initiator of the proposal.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A proposal account to keep the queued instruction."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create a proposal account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "program",
+          "type": "publicKey"
+        },
+        {
+          "name": "keys",
+          "type": {
+            "vec": {
+              "defined": "ProposalAccount"
+            }
+          }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelProposal",
+      "docs": [
+        "Cancels a queued proposal.",
+        "",
+        "It closes the proposal account without executing the proposal",
+        "instruction.  The creator of the proposal can cancel it by",
+        "itself.  Otherwise, it requires the m-of-n quorum, passed as",
+        "the signer remaining accounts.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to cancel the first pending proposal on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let creator = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, creator.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", creator.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Gets the first pending proposal.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "let proposal = state",
+        ".queue",
+        ".into_iter()",
+        ".find(|pubkey| program.account::<multisig_lite::Proposal>(*pubkey).is_ok())",
+        ".ok_or(\"no pending proposal\")?;",
+        "",
+        "// Cancels the proposal.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::CancelProposal {",
+        "signer: creator.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal,",
+        "})",
+        ".args(multisig_lite::instruction::CancelProposal { fund_bump })",
+        ".signer(creator.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "A canceler of the proposal.",
+            "",
+            "It should be one of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to cancel.",
+            "",
+            "It's closed and the rent goes back to the multisig fund."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveProposal",
+      "docs": [
+        "Approves the queued proposal.",
+        "",
+        "It only records the approval of the signer.  The proposal",
+        "is executed by [`multisig_lite::execute_proposal`] once it",
+        "meets the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to approve pending proposals on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Approves the pending proposals one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for proposal_pubkey in state.queue {",
+        "if program.account::<multisig_lite::Proposal>(proposal_pubkey).is_err() {",
+        "continue; // Skips the transfers.",
+        "}",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ApproveProposal {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "proposal: proposal_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ApproveProposal {})",
+        ".signer(signer.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "An approver of the proposal."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to approve."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "docs": [
+        "Executes the approved proposal.",
+        "",
+        "It executes the proposal instruction with the multisig fund PDA",
+        "signature and closes the proposal account.  The signer with the",
+        "[`Role::EXECUTOR`] role can execute the proposal once it meets",
+        "the m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to execute the approved proposals on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let executor = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, executor.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", executor.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Executes the pending proposals one by one.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "for proposal_pubkey in state.queue {",
+        "let proposal: multisig_lite::Proposal = match program.account(proposal_pubkey) {",
+        "Ok(proposal) => proposal,",
+        "Err(_) => continue, // Skips the transfers.",
+        "};",
+        "",
+        "// The proposal instruction accounts, except the multisig fund PDA,",
+        "// and the program to execute.",
+        "let mut remaining_accounts: Vec<_> = proposal",
+        ".keys",
+        ".iter()",
+        ".filter(|key| key.pubkey != fund_pda)",
+        ".map(|key| AccountMeta {",
+        "pubkey: key.pubkey,",
+        "is_signer: key.is_signer,",
+        "is_writable: key.is_writable,",
+        "})",
+        ".collect();",
+        "remaining_accounts.push(AccountMeta::new_readonly(proposal.program, false));",
+        "",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ExecuteProposal {",
+        "executor: executor.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "proposal: proposal_pubkey,",
+        "})",
+        ".args(multisig_lite::instruction::ExecuteProposal { fund_bump })",
+        ".accounts(remaining_accounts)",
+        ".signer(executor.as_ref())",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "An executor of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account, which signs the proposal instruction.",
+            ""
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A queued proposal account to execute."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addSigner",
+      "docs": [
+        "Adds a new signer to the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The new signer votes with the weight of one.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to add a new signer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Adds a new signer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::AddSigner {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::AddSigner {",
+        "pubkey: Pubkey::new_unique(),",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the additional rent for the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to pay the additional rent."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeSigner",
+      "docs": [
+        "Removes the signer from the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The threshold should be lowered in advance in case it's",
+        "higher than the summed weight of the remaining signers.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to remove the signer on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Removes the signer.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::RemoveSigner {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::RemoveSigner {",
+        "pubkey: Pubkey::new_unique(),",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It receives the refunded rent of the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to realloc the state account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "changeThreshold",
+      "docs": [
+        "Changes the threshold of the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, with the current threshold.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to change the threshold on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Changes the threshold to 2.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ChangeThreshold {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "})",
+        ".args(multisig_lite::instruction::ChangeThreshold { m: 2 })",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "m",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setRoles",
+      "docs": [
+        "Sets the roles of the signer.",
+        "",
+        "The [`Role`] flags limit what the signer can do, e.g. an",
+        "accounting bot may propose the transfers but not approve them.",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts, and is not allowed while there are pending transfers.",
+        "The number of the approvers should not go below the threshold.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to make a signer a proposer only on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Makes the bot a proposer only.",
+        "let bot = Pubkey::new_unique(); // one of the multisig signers.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::SetRoles {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "})",
+        ".args(multisig_lite::instruction::SetRoles {",
+        "pubkey: bot,",
+        "roles: multisig_lite::Role::PROPOSER,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pubkey",
+          "type": "publicKey"
+        },
+        {
+          "name": "roles",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resizeQueue",
+      "docs": [
+        "Resizes the transfer queue of the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts.  The signer pays the additional rent for the larger",
+        "queue, or gets back the rent for the smaller one.  The queue",
+        "can't be shrunk below the number of the pending transactions.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to resize the transfer queue on Devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "use solana_sdk::system_program;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Grows the transfer queue to 20.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::ResizeQueue {",
+        "signer: signer.pubkey(),",
+        "state: state_pda,",
+        "system_program: system_program::id(),",
+        "})",
+        ".args(multisig_lite::instruction::ResizeQueue { q: 20 })",
+        ".accounts(remaining_accounts)",
+        ".signer(signer.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account.",
+            "",
+            "It pays the additional rent, or receives the refunded rent,",
+            "of the multisig state account."
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to realloc the state account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "q",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "close",
+      "docs": [
+        "Closes the multisig account.",
+        "",
+        "It requires the m-of-n quorum, passed as the signer remaining",
+        "accounts together with the queued transfer accounts.",
+        "All the queued accounts should be passed in and writable, and",
+        "any other remaining account is rejected unless it's a co-signer.",
+        "",
+        "It cleans up all the remaining accounts and sends the remaining",
+        "fund to the destination.  The rent of the state account goes",
+        "back to the funder, original creator of the multisig account.",
+        "The tokens held by the multisig fund token accounts are not",
+        "swept, so please transfer those out with the token transfers",
+        "before closing the multisig account.  The spending limit",
+        "accounts should be removed by [`multisig_lite::remove_spending_limit`]",
+        "as well.",
+        "",
+        "# Examples",
+        "",
+        "Here is how you close the multisig account on devnet:",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::instruction::AccountMeta;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b\"fund\", state_pda.as_ref()], &pid);",
+        "",
+        "// Other signers to meet the m-of-n quorum.",
+        "let cosigners = [",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer1.json\").as_ref())?,",
+        "read_keypair_file(shellexpand::tilde(\"~/.config/solana/signer2.json\").as_ref())?,",
+        "];",
+        "let mut remaining_accounts: Vec<_> = cosigners",
+        ".iter()",
+        ".map(|cosigner| AccountMeta {",
+        "pubkey: cosigner.pubkey(),",
+        "is_signer: true,",
+        "is_writable: false,",
+        "})",
+        ".collect();",
+        "",
+        "// Gets the remaining transfers to collects the rents.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "remaining_accounts.extend(state.queue.into_iter().map(|pubkey| AccountMeta {",
+        "pubkey,",
+        "is_signer: false,",
+        "is_writable: true,",
+        "}));",
+        "",
+        "// close the multisig account.",
+        "let sig = program",
+        ".request()",
+        ".accounts(multisig_lite::accounts::Close {",
+        "signer: funder.pubkey(),",
+        "funder: funder.pubkey(),",
+        "state: state_pda,",
+        "fund: fund_pda,",
+        "destination: funder.pubkey(),",
+        "})",
+        ".args(multisig_lite::instruction::Close {",
+        "_index: index,",
+        "_state_bump: state_bump,",
+        "fund_bump,",
+        "})",
+        ".accounts(remaining_accounts)",
+        ".signer(funder.as_ref())",
+        ".signer(&cosigners[0])",
+        ".signer(&cosigners[1])",
+        ".send()?;",
+        "",
+        "println!(\"{sig}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "One of the signers of the multisig account."
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "An original funder of the multisig account.",
+            "",
+            "It gets back the rent of the multisig state account.",
+            ""
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig state PDA account."
+          ]
+        },
+        {
+          "name": "fund",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A multisig fund PDA account.",
+            ""
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "A destination of the remaining fund.",
+            ""
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u64"
+        },
+        {
+          "name": "stateBump",
+          "type": "u8"
+        },
+        {
+          "name": "fundBump",
+          "type": "u8"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "state",
+      "docs": [
+        "A multisig [`State`] PDA account data.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`State`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Print out the state account.",
+        "println!(\"{state:?}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "m",
+            "docs": [
+              "A threshold, in the summed [`State::weights`] of the approvals."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "An array of signers Pubkey, in the order given at the creation."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "roles",
+            "docs": [
+              "An array of the signer [`Role`] flags.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the [`State::signers`] array."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "weights",
+            "docs": [
+              "An array of the signer voting weights.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the [`State::signers`] array."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "fund",
+            "docs": [
+              "A fund PDA account, holding the native SOL."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "A balance of the fund in lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pending",
+            "docs": [
+              "A total lamports reserved by the pending transactions."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "transfers",
+            "docs": [
+              "A number of the transfers created so far.",
+              "",
+              "It's used as the index of the next [`Transfer`] PDA account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "q",
+            "docs": [
+              "A limit of the pending transactions."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "An array of the pending transactions."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "pendingTokens",
+            "docs": [
+              "An array of the token amounts reserved by the pending token",
+              "transfers, one for each mint."
+            ],
+            "type": {
+              "vec": {
+                "defined": "PendingToken"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "transfer",
+      "docs": [
+        "A multisig [`Transfer`] account data.",
+        "",
+        "The account is a PDA of the multisig state and the index,",
+        "which is the [`State::transfers`] at the creation.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Transfer`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued transfers.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Transfer` accounts iteratively.",
+        "for transfer in state.queue {",
+        "let transfer: multisig_lite::Transfer = match program.account(transfer) {",
+        "Ok(transfer) => transfer,",
+        "Err(_) => continue, // Skips the proposals.",
+        "};",
+        "println!(\"{transfer:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the transfer, one of the multisig",
+              "signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "A recipient of the transfer."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A lamports to transfer.",
+              "",
+              "It's the token amount in case of the token transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "A token mint of the transfer.",
+              "",
+              "It's `None` for the native SOL transfer."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "executeAfter",
+            "docs": [
+              "An earliest execution time of the transfer, in unix timestamp.",
+              "",
+              "The approved transfer stays in the queue until then."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "expireAt",
+            "docs": [
+              "An expiration time of the transfer, in unix timestamp.",
+              "",
+              "The expired transfer can't be approved nor executed, and",
+              "will be pruned by [`multisig_lite::prune_expired`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "memo",
+            "docs": [
+              "A memo, e.g. the invoice reference, of the transfer.",
+              "",
+              "It's recorded through the SPL Memo program at the execution."
+            ],
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the transfer.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "batch",
+      "docs": [
+        "A multisig [`Batch`] account data.",
+        "",
+        "It keeps the list of the native SOL payments, approved as one",
+        "unit by the m number of signers and executed across one or more",
+        "transactions by [`multisig_lite::execute_batch`].",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Batch`] accounts on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued batches.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Batch` accounts iteratively.",
+        "for batch in state.queue {",
+        "let batch: multisig_lite::Batch = match program.account(batch) {",
+        "Ok(batch) => batch,",
+        "Err(_) => continue, // Skips the transfers and the proposals.",
+        "};",
+        "println!(\"{batch:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the batch, one of the multisig signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payments",
+            "docs": [
+              "Payments of the batch."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Payment"
+              }
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "A number of the payments already executed.",
+              "",
+              "The payments are executed in order, starting from this index."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the batch.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "docs": [
+        "A multisig [`Proposal`] account data.",
+        "",
+        "It keeps an arbitrary instruction, which will be executed",
+        "with the multisig fund PDA signature once it meets the",
+        "m number of signers approval.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`Proposal`] accounts on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let funder = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", funder.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::State` account to get the queued proposals.",
+        "let state: multisig_lite::State = program.account(state_pda)?;",
+        "",
+        "// Query the `multisig_lite::Proposal` accounts iteratively.",
+        "for proposal in state.queue {",
+        "let proposal: multisig_lite::Proposal = match program.account(proposal) {",
+        "Ok(proposal) => proposal,",
+        "Err(_) => continue, // Skips the transfers.",
+        "};",
+        "println!(\"{proposal:?}\");",
+        "}",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "An creator of the proposal, one of the multisig",
+              "signers."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "program",
+            "docs": [
+              "A program to execute the instruction."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "docs": [
+              "Accounts of the instruction."
+            ],
+            "type": {
+              "vec": {
+                "defined": "ProposalAccount"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Data of the instruction."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "A current signed state of the proposal.",
+              "",
+              "It's indexed by the position of the signer in",
+              "the multisig [`State::signers`] array."
+            ],
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "A multisig [`SpendingLimit`] account data.",
+        "",
+        "It allows the signer to transfer the lamports from the multisig",
+        "fund up to the limit per period, without the m-of-n approval.",
+        "The account is a PDA of the multisig state and the signer, and",
+        "created and removed only by the m-of-n quorum.",
+        "",
+        "# Examples",
+        "",
+        "Here is how to query the [`SpendingLimit`] PDA account on Devnet.",
+        "",
+        "```no_run",
+        "use std::rc::Rc;",
+        "",
+        "use solana_sdk::commitment_config::CommitmentConfig;",
+        "use solana_sdk::pubkey::Pubkey;",
+        "use solana_sdk::signature::read_keypair_file;",
+        "use solana_sdk::signer::Signer;",
+        "",
+        "use anchor_client::{Client, Cluster};",
+        "",
+        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
+        "let url = Cluster::Devnet;",
+        "let signer = Rc::new(read_keypair_file(",
+        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
+        ")?);",
+        "let opts = CommitmentConfig::processed();",
+        "let pid = multisig_lite::id();",
+        "let program = Client::new_with_options(url, signer.clone(), opts).program(pid);",
+        "",
+        "// Gets the PDAs.",
+        "let index = 0u64; // the first multisig account of the funder.",
+        "let (state_pda, _state_bump) = Pubkey::find_program_address(",
+        "&[b\"state\", signer.pubkey().as_ref(), &index.to_le_bytes()],",
+        "&pid,",
+        ");",
+        "let (limit_pda, _limit_bump) = Pubkey::find_program_address(",
+        "&[b\"limit\", state_pda.as_ref(), signer.pubkey().as_ref()],",
+        "&pid,",
+        ");",
+        "",
+        "// Query the `multisig_lite::SpendingLimit` account.",
+        "let limit: multisig_lite::SpendingLimit = program.account(limit_pda)?;",
+        "",
+        "println!(\"{limit:?}\");",
+        "# Ok(())",
+        "# }",
+        "```"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "state",
+            "docs": [
+              "A multisig state PDA account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "signer",
+            "docs": [
+              "A signer allowed to spend."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A maximum lamports to spend per period."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "docs": [
+              "A period of the spending limit."
+            ],
+            "type": {
+              "defined": "Period"
+            }
+          },
+          {
+            "name": "spent",
+            "docs": [
+              "Lamports spent in the current period."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodStart",
+            "docs": [
+              "A start of the current period, in unix timestamp."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "PendingToken",
+      "docs": [
+        "A token amount reserved by the pending token transfers of the mint.",
+        "",
+        "It's kept in [`State::pending_tokens`], so that the queued token",
+        "transfers don't exceed the multisig fund token balance."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "A token mint."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "A token amount reserved by the pending token transfers."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Payment",
+      "docs": [
+        "A payment of the [`Batch`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "A recipient of the payment."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "A lamports to pay."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalAccount",
+      "docs": [
+        "An account of the [`Proposal`] instruction.",
+        "",
+        "It mirrors [`AccountMeta`], which is not serializable by Anchor."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "docs": [
+              "A public key of the account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "docs": [
+              "True if the instruction requires the signature of the account."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "docs": [
+              "True if the account data or the lamports may be mutated."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Period",
+      "docs": [
+        "A period of the [`SpendingLimit`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Day"
+          },
+          {
+            "name": "Week"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "m",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "weights",
+          "type": "bytes",
+          "index": false
+        },
+        {
+          "name": "q",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Funded",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "balance",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BalanceSynced",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "balance",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pending",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferPruned",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Approved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Revoked",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transfer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Spent",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "spent",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payments",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchApproved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BatchExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "batch",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "executed",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalCreated",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "program",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalCancelled",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalApproved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "program",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "queueLen",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SignerAdded",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SignerRemoved",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "m",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RolesChanged",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pubkey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roles",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "QueueResized",
+      "fields": [
+        {
+          "name": "state",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
         },
+        {
+          "name": "q",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Closed",
+      "fields": [
         {
           "name": "state",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "A multisig state PDA account."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "fund",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "A multisig fund PDA account.",
-            ""
-          ]
-        }
-      ],
-      "args": [
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "stateBump",
-          "type": "u8"
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "fundBump",
-          "type": "u8"
+          "name": "lamports",
+          "type": "u64",
+          "index": false
         }
       ]
     }
   ],
-  "accounts": [
-    {
-      "name": "state",
-      "docs": [
-        "A multisig [`State`] PDA account data.",
-        "",
-        "# Examples",
-        "",
-        "Here is how to query the [`State`] PDA account on Devnet.",
-        "",
-        "```no_run",
-        "use std::rc::Rc;",
-        "",
-        "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::pubkey::Pubkey;",
-        "use solana_sdk::signature::read_keypair_file;",
-        "use solana_sdk::signer::Signer;",
-        "",
-        "use anchor_client::{Client, Cluster};",
-        "",
-        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
-        "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
-        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
-        ")?);",
-        "let opts = CommitmentConfig::processed();",
-        "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
-        "",
-        "// Gets the PDAs.",
-        "let (state_pda, _state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
-        "",
-        "// Query the `multisig_lite::State` account.",
-        "let state: multisig_lite::State = program.account(state_pda)?;",
-        "",
-        "// Print out the state account.",
-        "println!(\"{state:?}\");",
-        "# Ok(())",
-        "# }",
-        "```"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "m",
-            "docs": [
-              "A threshold."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "signers",
-            "docs": [
-              "An array of signers Pubkey."
-            ],
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "signed",
-            "docs": [
-              "A current signed state."
-            ],
-            "type": {
-              "vec": "bool"
-            }
-          },
-          {
-            "name": "fund",
-            "docs": [
-              "A fund PDA account, holding the native SOL."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "balance",
-            "docs": [
-              "A balance of the fund in lamports."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "q",
-            "docs": [
-              "A limit of the pending transactions."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "queue",
-            "docs": [
-              "An array of the pending transactions."
-            ],
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "transfer",
-      "docs": [
-        "A multisig [`Transfer`] account data.",
-        "",
-        "# Examples",
-        "",
-        "Here is how to query the [`Transfer`] PDA account on Devnet.",
-        "",
-        "```no_run",
-        "use std::rc::Rc;",
-        "",
-        "use solana_sdk::commitment_config::CommitmentConfig;",
-        "use solana_sdk::pubkey::Pubkey;",
-        "use solana_sdk::signature::read_keypair_file;",
-        "use solana_sdk::signer::Signer;",
-        "",
-        "use anchor_client::{Client, Cluster};",
-        "",
-        "# fn main() -> Result<(), Box<dyn std::error::Error>> {",
-        "let url = Cluster::Devnet;",
-        "let funder = Rc::new(read_keypair_file(",
-        "shellexpand::tilde(\"~/.config/solana/id.json\").as_ref(),",
-        ")?);",
-        "let opts = CommitmentConfig::processed();",
-        "let pid = multisig_lite::id();",
-        "let program = Client::new_with_options(url, funder.clone(), opts).program(pid);",
-        "",
-        "// Gets the PDAs.",
-        "let (state_pda, _state_bump) =",
-        "Pubkey::find_program_address(&[b\"state\", funder.pubkey().as_ref()], &pid);",
-        "",
-        "// Query the `multisig_lite::State` account to get the queued transfers.",
-        "let state: multisig_lite::State = program.account(state_pda)?;",
-        "",
-        "// Query the `multisig_lite::Transfer` accounts iteratively.",
-        "for transfer in state.queue {",
-        "let transfer: multisig_lite::Transfer = program.account(transfer)?;",
-        "println!(\"{transfer:?}\");",
-        "}",
-        "# Ok(())",
-        "# }",
-        "```"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "docs": [
-              "An creator of the transfer, one of the multisig",
-              "signers."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "recipient",
-            "docs": [
-              "A recipient of the transfer."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "lamports",
-            "docs": [
-              "A lamports to transfer."
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
   "errors": [
     {
       "code": 6000,
@@ -1796,6 +10227,139 @@ export const IDL: MultisigLite = {
       "code": 6012,
       "name": "NotEnoughFundBalance",
       "msg": "There is not enough fund balance"
+    },
+    {
+      "code": 6013,
+      "name": "TransferNotQueued",
+      "msg": "Transfer is not queued in the multisig account"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidRecipient",
+      "msg": "Invalid transfer recipient"
+    },
+    {
+      "code": 6015,
+      "name": "ThresholdNotMet",
+      "msg": "Threshold not met"
+    },
+    {
+      "code": 6016,
+      "name": "NotApproved",
+      "msg": "Transfer is not approved by the signer"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidMint",
+      "msg": "Invalid token mint for the transfer"
+    },
+    {
+      "code": 6018,
+      "name": "ProposalNotQueued",
+      "msg": "Proposal is not queued in the multisig account"
+    },
+    {
+      "code": 6019,
+      "name": "TransferExpired",
+      "msg": "Transfer had been expired"
+    },
+    {
+      "code": 6020,
+      "name": "TransferNotExpired",
+      "msg": "Transfer is not expired yet"
+    },
+    {
+      "code": 6021,
+      "name": "TransferTimeLocked",
+      "msg": "Transfer is time-locked"
+    },
+    {
+      "code": 6022,
+      "name": "SpendingLimitExceeded",
+      "msg": "Spending limit is exceeded"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidSpendingLimit",
+      "msg": "Invalid spending limit account"
+    },
+    {
+      "code": 6024,
+      "name": "NotProposer",
+      "msg": "Signer is not a proposer"
+    },
+    {
+      "code": 6025,
+      "name": "NotApprover",
+      "msg": "Signer is not an approver"
+    },
+    {
+      "code": 6026,
+      "name": "NotExecutor",
+      "msg": "Signer is not an executor"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidWeights",
+      "msg": "Invalid signer weights"
+    },
+    {
+      "code": 6028,
+      "name": "DuplicateSigner",
+      "msg": "Duplicate signer"
+    },
+    {
+      "code": 6029,
+      "name": "QueueTooShort",
+      "msg": "Queue is too short"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPayments",
+      "msg": "Invalid batch payments"
+    },
+    {
+      "code": 6031,
+      "name": "BatchNotQueued",
+      "msg": "Batch is not queued"
+    },
+    {
+      "code": 6032,
+      "name": "MemoTooLong",
+      "msg": "Memo is too long"
+    },
+    {
+      "code": 6033,
+      "name": "UnexpectedAccount",
+      "msg": "Unexpected remaining account"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidQueuedAccount",
+      "msg": "Invalid queued account"
+    },
+    {
+      "code": 6035,
+      "name": "LamportsOverflow",
+      "msg": "Lamports overflow"
+    },
+    {
+      "code": 6036,
+      "name": "LamportsUnderflow",
+      "msg": "Lamports underflow"
+    },
+    {
+      "code": 6037,
+      "name": "FundBelowReserve",
+      "msg": "Fund is below the reserve"
+    },
+    {
+      "code": 6038,
+      "name": "MissingQueuedAccount",
+      "msg": "Missing queued account"
     }
-  ]
+  ],
+  "metadata": {
+    "address": "Ecycmji8eeggXrA3rD2cdEHpHDnP4btvVfcyTBS9cG9t"
+  }
 };
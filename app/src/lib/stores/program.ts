@@ -1,6 +1,6 @@
 import { get, writable } from 'svelte/store';
 import { PublicKey } from '@solana/web3.js';
-import { utils, Program } from '@project-serum/anchor';
+import { utils, BN, Program } from '@project-serum/anchor';
 import multisigIdl from '$lib/idl/multisig_lite.json';
 import type { Provider } from '@project-serum/anchor';
 
@@ -21,12 +21,16 @@ class Multisig {
 		}
 		const programId = idl.metadata.address;
 		const program = new Program(idl, programId, provider);
-		const [statePda, stateBump] = await this.findPda(
-			program.programId,
-			'state',
-			program.provider.publicKey
-		);
-		const [fundPda, fundBump] = await this.findPda(program.programId, 'fund', statePda);
+		// Hard coded the first multisig account of the funder for now.
+		const index = new BN(0);
+		const [statePda, stateBump] = await this.findPda(program.programId, 'state', [
+			program.provider.publicKey.toBuffer(),
+			index.toArrayLike(Buffer, 'le', 8)
+		]);
+		const [fundPda, fundBump] = await this.findPda(program.programId, 'fund', [
+			statePda.toBuffer()
+		]);
+		this.index = index;
 		this.statePda = statePda;
 		this.stateBump = stateBump;
 		this.fundPda = fundPda;
@@ -51,9 +55,10 @@ class Multisig {
 			// Hard coded values for now.
 			const m = 1;
 			const signers = [PublicKey.unique(), this.publicKey];
+			const weights = Buffer.from([1, 1]);
 			const maxQueue = 10;
 			await this.program.methods
-				.create(m, signers, maxQueue, this.stateBump, this.fundBump)
+				.create(m, signers, weights, maxQueue, this.index, this.stateBump, this.fundBump)
 				.accounts({
 					funder: this.publicKey,
 					state: this.statePda,
@@ -68,13 +73,23 @@ class Multisig {
 
 	async close(address: PublicKey) {
 		if (address.equals(this.statePda)) {
+			// Collects the rent fee of the queued accounts, too.
+			const state = await this.program.account.state.fetch(this.statePda);
+			const queue = state.queue.map((pubkey: PublicKey) => ({
+				pubkey,
+				isSigner: false,
+				isWritable: true
+			}));
 			await this.program.methods
-				.close(this.stateBump, multisig.fundBump)
+				.close(this.index, this.stateBump, this.fundBump)
 				.accounts({
+					signer: this.publicKey,
 					funder: this.publicKey,
 					state: this.statePda,
-					fund: this.fundPda
+					fund: this.fundPda,
+					destination: this.publicKey
 				})
+				.remainingAccounts(queue)
 				.rpc();
 		}
 	}
@@ -82,10 +97,10 @@ class Multisig {
 	async findPda(
 		programId: PublicKey,
 		name: string,
-		publicKey: PublicKey
+		seeds: Buffer[]
 	): Promise<[PublicKey, number]> {
 		return await PublicKey.findProgramAddress(
-			[utils.bytes.utf8.encode(name), publicKey.toBuffer()],
+			[utils.bytes.utf8.encode(name), ...seeds],
 			programId
 		);
 	}
@@ -10,7 +10,6 @@ use std::rc::Rc;
 
 use clap::{Parser, ValueEnum};
 use solana_sdk::commitment_config::CommitmentConfig;
-use solana_sdk::instruction::AccountMeta;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::read_keypair_file;
 use solana_sdk::signer::Signer;
@@ -22,6 +21,10 @@ struct Args {
     /// A Solana cluster.
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -52,45 +55,38 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[b"state", signer.pubkey().as_ref()], &pid);
-    let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            signer.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
 
-    // Gets the pending transfers and the recipients account info.
-    let mut remaining_accounts = vec![];
+    // Approves the pending transfers one by one.
     let state: multisig_lite::State = program.account(state_pda)?;
     for transfer_pubkey in state.queue {
-        let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;
+        if program
+            .account::<multisig_lite::Transfer>(transfer_pubkey)
+            .is_err()
+        {
+            continue; // Skips the proposals.
+        }
 
-        // Pushes the transfer account.
-        remaining_accounts.push(AccountMeta {
-            pubkey: transfer_pubkey,
-            is_signer: false,
-            is_writable: true,
-        });
+        let sig = program
+            .request()
+            .accounts(multisig_lite::accounts::Approve {
+                signer: signer.pubkey(),
+                state: state_pda,
+                transfer: transfer_pubkey,
+            })
+            .args(multisig_lite::instruction::Approve {})
+            .signer(signer.as_ref())
+            .send()?;
 
-        // Pushes the recipient account.
-        remaining_accounts.push(AccountMeta {
-            pubkey: transfer.recipient,
-            is_signer: false,
-            is_writable: true,
-        });
+        println!("{sig}");
     }
 
-    // Approve the multisig account.
-    let sig = program
-        .request()
-        .accounts(multisig_lite::accounts::Approve {
-            signer: signer.pubkey(),
-            state: state_pda,
-            fund: fund_pda,
-        })
-        .args(multisig_lite::instruction::Approve { fund_bump })
-        .accounts(remaining_accounts)
-        .signer(signer.as_ref())
-        .send()?;
-
-    println!("{sig}");
-
     Ok(())
 }
@@ -0,0 +1,95 @@
+//! `multisig_list::multisig_list::cancel_transfer` instruction example.
+//!
+//! Run with:
+//! ```
+//! cargo run --example cancel-transfer
+//! ```
+
+use std::error::Error;
+use std::rc::Rc;
+
+use clap::{Parser, ValueEnum};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::read_keypair_file;
+use solana_sdk::signer::Signer;
+
+use anchor_client::{Client, Cluster};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// A Solana cluster.
+    #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
+    cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum ClusterArg {
+    Mainnet,
+    Devnet,
+    Localnet,
+}
+
+impl From<ClusterArg> for Cluster {
+    fn from(arg: ClusterArg) -> Self {
+        match arg {
+            ClusterArg::Mainnet => Self::Mainnet,
+            ClusterArg::Devnet => Self::Devnet,
+            ClusterArg::Localnet => Self::Localnet,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let creator = Rc::new(read_keypair_file(
+        shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    )?);
+    let url = args.cluster.into();
+    let opts = CommitmentConfig::processed();
+    let pid = multisig_lite::id();
+    let program = Client::new_with_options(url, creator.clone(), opts).program(pid);
+
+    // Gets the PDAs.
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            creator.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
+    let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+    // Cancels the pending transfers created by the creator.
+    let state: multisig_lite::State = program.account(state_pda)?;
+    for transfer_pubkey in state.queue {
+        let transfer: multisig_lite::Transfer = match program.account(transfer_pubkey) {
+            Ok(transfer) => transfer,
+            Err(_) => continue, // Skips the proposals.
+        };
+        if transfer.creator != creator.pubkey() {
+            continue;
+        }
+
+        let sig = program
+            .request()
+            .accounts(multisig_lite::accounts::CancelTransfer {
+                signer: creator.pubkey(),
+                state: state_pda,
+                fund: fund_pda,
+                transfer: transfer_pubkey,
+            })
+            .args(multisig_lite::instruction::CancelTransfer { fund_bump })
+            .signer(creator.as_ref())
+            .send()?;
+
+        println!("{sig}");
+    }
+
+    Ok(())
+}
@@ -22,6 +22,14 @@ struct Args {
     /// A Solana cluster.
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+
+    /// Keypair files of other signers to meet the m-of-n quorum.
+    #[arg(long)]
+    cosigner: Vec<String>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -52,37 +60,60 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
     let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
-    // Gets the remaining transfers to collects the rents.
-    let state: multisig_lite::State = program.account(state_pda)?;
-    let remaining_accounts: Vec<_> = state
-        .queue
-        .into_iter()
-        .map(|pubkey| AccountMeta {
-            pubkey,
-            is_signer: false,
-            is_writable: true,
+    // Other signers to meet the m-of-n quorum.
+    let mut cosigners = vec![];
+    for path in &args.cosigner {
+        cosigners.push(read_keypair_file(shellexpand::tilde(path).as_ref())?);
+    }
+    let mut remaining_accounts: Vec<_> = cosigners
+        .iter()
+        .map(|cosigner| AccountMeta {
+            pubkey: cosigner.pubkey(),
+            is_signer: true,
+            is_writable: false,
         })
         .collect();
 
-    // Closes the multisig account.
-    let sig = program
+    // Gets the remaining transfers to collects the rents.
+    let state: multisig_lite::State = program.account(state_pda)?;
+    remaining_accounts.extend(state.queue.into_iter().map(|pubkey| AccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable: true,
+    }));
+
+    // Closes the multisig account and sends the remaining fund
+    // back to the funder.
+    let mut request = program
         .request()
         .accounts(multisig_lite::accounts::Close {
+            signer: funder.pubkey(),
             funder: funder.pubkey(),
             state: state_pda,
             fund: fund_pda,
+            destination: funder.pubkey(),
         })
         .args(multisig_lite::instruction::Close {
+            _index: args.index,
             _state_bump: state_bump,
             fund_bump,
         })
         .accounts(remaining_accounts)
-        .signer(funder.as_ref())
-        .send()?;
+        .signer(funder.as_ref());
+    for cosigner in &cosigners {
+        request = request.signer(cosigner);
+    }
+    let sig = request.send()?;
 
     println!("{sig}");
 
@@ -12,7 +12,7 @@ use clap::{Parser, ValueEnum};
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::read_keypair_file;
-use solana_sdk::signer::{keypair::Keypair, Signer};
+use solana_sdk::signer::Signer;
 use solana_sdk::system_program;
 
 use anchor_client::{Client, Cluster};
@@ -23,9 +23,25 @@ struct Args {
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
 
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+
     /// Lamports to fund to the multisig fund account, 0.001SOL by default.
     #[arg(short, long, default_value_t = 1_000_000)]
     lamports: u64,
+
+    /// An earliest execution time of the transfer, in unix timestamp.
+    #[arg(short, long)]
+    execute_after: Option<i64>,
+
+    /// An expiration time of the transfer, in unix timestamp.
+    #[arg(long)]
+    expire_at: Option<i64>,
+
+    /// A memo of the transfer, e.g. the invoice reference.
+    #[arg(short, long)]
+    memo: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -56,16 +72,26 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
     let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
-    // Temporary transfer keypair.
-    //
-    // This is only required for the transaction signature and
-    // won't be required once the transaction is recorded on the
-    // ledger.
-    let transfer = Keypair::new();
+    // Gets the next transfer PDA.
+    let state: multisig_lite::State = program.account(state_pda)?;
+    let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(
+        &[
+            b"transfer",
+            state_pda.as_ref(),
+            &state.transfers.to_le_bytes(),
+        ],
+        &pid,
+    );
 
     // Creates a pending transfer.
     let sig = program
@@ -74,16 +100,18 @@ fn main() -> Result<(), Box<dyn Error>> {
             creator: funder.pubkey(),
             state: state_pda,
             fund: fund_pda,
-            transfer: transfer.pubkey(),
+            transfer: transfer_pda,
             system_program: system_program::id(),
         })
         .args(multisig_lite::instruction::CreateTransfer {
             recipient: Pubkey::new_unique(),
             lamports: args.lamports,
+            execute_after: args.execute_after,
+            expire_at: args.expire_at,
+            memo: args.memo,
             fund_bump,
         })
         .signer(funder.as_ref())
-        .signer(&transfer)
         .send()?;
 
     println!("{sig}");
@@ -23,6 +23,10 @@ struct Args {
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
 
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+
     /// A threshold of multisig account, e.g. m in m/n.
     #[arg(short, long = "threshold", default_value_t = 2)]
     m: u8,
@@ -60,8 +64,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
     let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
     // Creates a multisig account.
@@ -76,7 +86,9 @@ fn main() -> Result<(), Box<dyn Error>> {
         .args(multisig_lite::instruction::Create {
             m: args.m,
             signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],
+            weights: vec![1, 1, 1],
             q: args.q,
+            _index: args.index,
             _state_bump: state_bump,
             fund_bump,
         })
@@ -0,0 +1,104 @@
+//! `multisig_list::multisig_list::execute` instruction example.
+//!
+//! Run with:
+//! ```
+//! cargo run --example execute
+//! ```
+
+use std::error::Error;
+use std::rc::Rc;
+
+use clap::{Parser, ValueEnum};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::read_keypair_file;
+use solana_sdk::signer::Signer;
+
+use anchor_client::{Client, Cluster};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// A Solana cluster.
+    #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
+    cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum ClusterArg {
+    Mainnet,
+    Devnet,
+    Localnet,
+}
+
+impl From<ClusterArg> for Cluster {
+    fn from(arg: ClusterArg) -> Self {
+        match arg {
+            ClusterArg::Mainnet => Self::Mainnet,
+            ClusterArg::Devnet => Self::Devnet,
+            ClusterArg::Localnet => Self::Localnet,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let signer = Rc::new(read_keypair_file(
+        shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    )?);
+    let url = args.cluster.into();
+    let opts = CommitmentConfig::processed();
+    let pid = multisig_lite::id();
+    let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+
+    // Gets the PDAs.
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            signer.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
+    let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+    // Executes the approved transfers one by one.
+    let state: multisig_lite::State = program.account(state_pda)?;
+    for transfer_pubkey in &state.queue {
+        let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {
+            Ok(transfer) => transfer,
+            Err(_) => continue, // Skips the proposals.
+        };
+        let approvals: usize = transfer
+            .signed
+            .iter()
+            .zip(&state.weights)
+            .filter(|(signed, _)| **signed)
+            .map(|(_, weight)| *weight as usize)
+            .sum();
+        if transfer.mint.is_some() || approvals < state.m as usize {
+            continue;
+        }
+
+        let sig = program
+            .request()
+            .accounts(multisig_lite::accounts::Execute {
+                executor: signer.pubkey(),
+                state: state_pda,
+                fund: fund_pda,
+                transfer: *transfer_pubkey,
+                recipient: transfer.recipient,
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::Execute { fund_bump })
+            .signer(signer.as_ref())
+            .send()?;
+
+        println!("{sig}");
+    }
+
+    Ok(())
+}
@@ -23,6 +23,10 @@ struct Args {
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
 
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
+
     /// Lamports to fund to the multisig fund account, 0.05SOL by default.
     #[arg(short, long, default_value_t = 50_000_000)]
     lamports: u64,
@@ -56,8 +60,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
     let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
     // Funds the multisig account.
@@ -71,6 +81,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         })
         .args(multisig_lite::instruction::Fund {
             lamports: args.lamports,
+            _index: args.index,
             _state_bump,
             fund_bump,
         })
@@ -21,6 +21,10 @@ struct Args {
     /// A Solana cluster.
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -51,8 +55,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
     let (fund_pda, _fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
     // Query the Fund account.
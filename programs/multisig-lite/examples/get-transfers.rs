@@ -21,6 +21,10 @@ struct Args {
     /// A Solana cluster.
     #[arg(short, long, value_enum, default_value_t = ClusterArg::Localnet)]
     cluster: ClusterArg,
+
+    /// An index of the multisig account of the funder.
+    #[arg(short, long, default_value_t = 0)]
+    index: u64,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -51,15 +55,24 @@ fn main() -> Result<(), Box<dyn Error>> {
     let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 
     // Gets the PDAs.
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    let (state_pda, _state_bump) = Pubkey::find_program_address(
+        &[
+            b"state",
+            funder.pubkey().as_ref(),
+            &args.index.to_le_bytes(),
+        ],
+        &pid,
+    );
 
     // Query the `multisig_lite::State` account to get the queued transfers.
     let state: multisig_lite::State = program.account(state_pda)?;
 
     // Query the `multisig_lite::Transfer` accounts iteratively.
     for transfer in state.queue {
-        let transfer: multisig_lite::Transfer = program.account(transfer)?;
+        let transfer: multisig_lite::Transfer = match program.account(transfer) {
+            Ok(transfer) => transfer,
+            Err(_) => continue, // Skips the proposals.
+        };
         println!("{transfer:?}");
     }
 
@@ -30,8 +30,11 @@
 //! let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 //!
 //! // Gets the PDAs.
-//! let (state_pda, state_bump) =
-//!     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+//! let index = 0u64; // the first multisig account of the funder.
+//! let (state_pda, state_bump) = Pubkey::find_program_address(
+//!     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+//!     &pid,
+//! );
 //! let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 //!
 //! // Creates a multisig account.
@@ -46,7 +49,9 @@
 //!     .args(multisig_lite::instruction::Create {
 //!         m: 2, // m as in m/n.
 //!         signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],
+//!         weights: vec![1, 1, 1],
 //!         q: 10, // transfer queue limit.
+//!         _index: index,
 //!         _state_bump: state_bump,
 //!         fund_bump,
 //!     })
@@ -58,12 +63,13 @@
 //! # }
 //! ```
 
-use std::collections::{HashMap, HashSet};
-use std::ops::DerefMut;
+use std::collections::HashMap;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 #[cfg(not(feature = "localnet"))]
 declare_id!("Ecycmji8eeggXrA3rD2cdEHpHDnP4btvVfcyTBS9cG9t");
@@ -124,6 +130,111 @@ pub enum Error {
     /// Not enough `Fund` balance.
     #[msg("There is not enough fund balance")]
     NotEnoughFundBalance,
+
+    /// [`Transfer`] is not queued in the multisig [`State`].
+    #[msg("Transfer is not queued in the multisig account")]
+    TransferNotQueued,
+
+    /// Invalid transfer recipient given.
+    #[msg("Invalid transfer recipient")]
+    InvalidRecipient,
+
+    /// The threshold, e.g. `m`, is not met by the signers.
+    #[msg("Threshold not met")]
+    ThresholdNotMet,
+
+    /// [`Transfer`] is not approved by the signer.
+    #[msg("Transfer is not approved by the signer")]
+    NotApproved,
+
+    /// Invalid token mint given for the [`Transfer`].
+    #[msg("Invalid token mint for the transfer")]
+    InvalidMint,
+
+    /// [`Proposal`] is not queued in the multisig [`State`].
+    #[msg("Proposal is not queued in the multisig account")]
+    ProposalNotQueued,
+
+    /// [`Transfer`] had been expired.
+    #[msg("Transfer had been expired")]
+    TransferExpired,
+
+    /// [`Transfer`] is not expired yet.
+    #[msg("Transfer is not expired yet")]
+    TransferNotExpired,
+
+    /// [`Transfer`] is time-locked.
+    #[msg("Transfer is time-locked")]
+    TransferTimeLocked,
+
+    /// [`SpendingLimit`] is exceeded in the current period.
+    #[msg("Spending limit is exceeded")]
+    SpendingLimitExceeded,
+
+    /// [`SpendingLimit`] is not for the multisig account or the signer.
+    #[msg("Invalid spending limit account")]
+    InvalidSpendingLimit,
+
+    /// Signer doesn't have the [`Role::PROPOSER`] role.
+    #[msg("Signer is not a proposer")]
+    NotProposer,
+
+    /// Signer doesn't have the [`Role::APPROVER`] role.
+    #[msg("Signer is not an approver")]
+    NotApprover,
+
+    /// Signer doesn't have the [`Role::EXECUTOR`] role.
+    #[msg("Signer is not an executor")]
+    NotExecutor,
+
+    /// Signer weights are not matching the signers or zero.
+    #[msg("Invalid signer weights")]
+    InvalidWeights,
+
+    /// Signer appears more than once.
+    #[msg("Duplicate signer")]
+    DuplicateSigner,
+
+    /// Queue is shorter than the pending transactions.
+    #[msg("Queue is too short")]
+    QueueTooShort,
+
+    /// Batch payments are empty or too many.
+    #[msg("Invalid batch payments")]
+    InvalidPayments,
+
+    /// Batch is not in the multisig queue.
+    #[msg("Batch is not queued")]
+    BatchNotQueued,
+
+    /// Transfer memo is longer than [`Transfer::MAX_MEMO`] bytes.
+    #[msg("Memo is too long")]
+    MemoTooLong,
+
+    /// Remaining account is neither a co-signer nor a queued account.
+    #[msg("Unexpected remaining account")]
+    UnexpectedAccount,
+
+    /// Queued account is not writable or not owned by the program.
+    #[msg("Invalid queued account")]
+    InvalidQueuedAccount,
+
+    /// Lamports addition overflows.
+    #[msg("Lamports overflow")]
+    LamportsOverflow,
+
+    /// Lamports subtraction underflows.
+    #[msg("Lamports underflow")]
+    LamportsUnderflow,
+
+    /// Multisig fund drops below the rent-exempt minimum plus the
+    /// lamports reserved by the queued transfers.
+    #[msg("Fund is below the reserve")]
+    FundBelowReserve,
+
+    /// Queued account is not passed in the remaining accounts.
+    #[msg("Missing queued account")]
+    MissingQueuedAccount,
 }
 
 /// A multisig [`State`] PDA account data.
@@ -152,8 +263,11 @@ pub enum Error {
 /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 ///
 /// // Gets the PDAs.
-/// let (state_pda, _state_bump) =
-///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
 ///
 /// // Query the `multisig_lite::State` account.
 /// let state: multisig_lite::State = program.account(state_pda)?;
@@ -166,14 +280,23 @@ pub enum Error {
 #[account]
 #[derive(Debug)]
 pub struct State {
-    /// A threshold.
+    /// A threshold, in the summed [`State::weights`] of the approvals.
     pub m: u8,
 
-    /// An array of signers Pubkey.
+    /// An array of signers Pubkey, in the order given at the creation.
     pub signers: Vec<Pubkey>,
 
-    /// A current signed state.
-    pub signed: Vec<bool>,
+    /// An array of the signer [`Role`] flags.
+    ///
+    /// It's indexed by the position of the signer in
+    /// the [`State::signers`] array.
+    pub roles: Vec<u8>,
+
+    /// An array of the signer voting weights.
+    ///
+    /// It's indexed by the position of the signer in
+    /// the [`State::signers`] array.
+    pub weights: Vec<u8>,
 
     /// A fund PDA account, holding the native SOL.
     pub fund: Pubkey,
@@ -181,11 +304,23 @@ pub struct State {
     /// A balance of the fund in lamports.
     pub balance: u64,
 
+    /// A total lamports reserved by the pending transactions.
+    pub pending: u64,
+
+    /// A number of the transfers created so far.
+    ///
+    /// It's used as the index of the next [`Transfer`] PDA account.
+    pub transfers: u64,
+
     /// A limit of the pending transactions.
     pub q: u8,
 
     /// An array of the pending transactions.
     pub queue: Vec<Pubkey>,
+
+    /// An array of the token amounts reserved by the pending token
+    /// transfers, one for each mint.
+    pub pending_tokens: Vec<PendingToken>,
 }
 
 impl State {
@@ -201,10 +336,11 @@ impl State {
     /// A maximum transaction queue.
     const MAX_QUEUE: u8 = u8::MAX;
 
-    fn space(signers: &[Pubkey], q: u8) -> usize {
-        let n = Self::valid_n(signers.len() as u8) as usize;
+    fn space(n: usize, q: u8) -> usize {
+        let n = Self::valid_n(n as u8) as usize;
         let q = Self::valid_q(q) as usize;
-        8 + 1 + 4 + 32 * n + 4 + n + 32 + 8 + 1 + 4 + 32 * q
+        let pending_tokens = 4 + PendingToken::SPACE * q;
+        8 + 1 + 4 + 32 * n + 4 + n + 4 + n + 32 + 8 + 8 + 8 + 1 + 4 + 32 * q + pending_tokens
     }
 
     /// Returns the valid n, number of signers.
@@ -217,11 +353,6 @@ impl State {
         q.clamp(Self::MIN_QUEUE, Self::MAX_QUEUE)
     }
 
-    /// Checks if the transfer queue is empty.
-    fn is_queue_empty(&self) -> bool {
-        self.queue.is_empty()
-    }
-
     /// Check if the multisig queue is full.
     fn is_queue_full(&self) -> bool {
         self.queue.len() == self.q as usize
@@ -229,11 +360,153 @@ impl State {
 
     /// Checks if the account had been locked.
     ///
-    /// The multisig account is locked once it's signed
-    /// by anyone.  It will be unlocked once the current
-    /// pending transactions were completed.
+    /// The signers of the multisig account are locked while
+    /// there are pending transfers, as those keep the approvals
+    /// indexed by the current signers.
     fn is_locked(&self) -> bool {
-        self.signed.iter().any(|signed| *signed)
+        !self.queue.is_empty()
+    }
+
+    /// Returns the index of the signer in the signers array.
+    #[allow(clippy::result_large_err)]
+    fn signer_index(&self, signer: &Pubkey) -> Result<usize> {
+        match self.signers.iter().position(|pubkey| pubkey == signer) {
+            None => Err(Error::InvalidSigner.into()),
+            Some(signer_index) => Ok(signer_index),
+        }
+    }
+
+    /// Checks if the signer has the role.
+    fn has_role(&self, signer_index: usize, role: u8) -> bool {
+        self.roles[signer_index] & role == role
+    }
+
+    /// Returns the total weight of the signers with the [`Role::APPROVER`] role.
+    fn approver_weight(&self) -> usize {
+        (0..self.signers.len())
+            .filter(|index| self.has_role(*index, Role::APPROVER))
+            .map(|index| self.weights[index] as usize)
+            .sum()
+    }
+
+    /// Returns the summed weight of the approvals in the signed state.
+    fn approvals(&self, signed: &[bool]) -> usize {
+        signed
+            .iter()
+            .zip(&self.weights)
+            .filter(|(signed, _)| **signed)
+            .map(|(_, weight)| *weight as usize)
+            .sum()
+    }
+
+    /// Checks if the signed state meets the threshold.
+    fn is_approved(&self, signed: &[bool]) -> bool {
+        self.approvals(signed) >= self.m as usize
+    }
+
+    /// Adds the lamports to the spendable balance.
+    #[allow(clippy::result_large_err)]
+    fn add_balance(&mut self, lamports: u64) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_add(lamports)
+            .ok_or(Error::LamportsOverflow)?;
+        Ok(())
+    }
+
+    /// Subtracts the lamports from the spendable balance.
+    #[allow(clippy::result_large_err)]
+    fn sub_balance(&mut self, lamports: u64) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_sub(lamports)
+            .ok_or(Error::LamportsUnderflow)?;
+        Ok(())
+    }
+
+    /// Adds the lamports to the pending lamports.
+    #[allow(clippy::result_large_err)]
+    fn add_pending(&mut self, lamports: u64) -> Result<()> {
+        self.pending = self
+            .pending
+            .checked_add(lamports)
+            .ok_or(Error::LamportsOverflow)?;
+        Ok(())
+    }
+
+    /// Subtracts the lamports from the pending lamports.
+    #[allow(clippy::result_large_err)]
+    fn sub_pending(&mut self, lamports: u64) -> Result<()> {
+        self.pending = self
+            .pending
+            .checked_sub(lamports)
+            .ok_or(Error::LamportsUnderflow)?;
+        Ok(())
+    }
+
+    /// Returns the token amount reserved by the pending token
+    /// transfers of the mint.
+    fn pending_token(&self, mint: &Pubkey) -> u64 {
+        self.pending_tokens
+            .iter()
+            .find(|pending| pending.mint == *mint)
+            .map_or(0, |pending| pending.amount)
+    }
+
+    /// Adds the token amount to the pending token transfers of the mint.
+    #[allow(clippy::result_large_err)]
+    fn add_pending_token(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let amount = self
+            .pending_token(mint)
+            .checked_add(amount)
+            .ok_or(Error::LamportsOverflow)?;
+        self.set_pending_token(mint, amount);
+        Ok(())
+    }
+
+    /// Subtracts the token amount from the pending token transfers
+    /// of the mint.
+    #[allow(clippy::result_large_err)]
+    fn sub_pending_token(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let amount = self
+            .pending_token(mint)
+            .checked_sub(amount)
+            .ok_or(Error::LamportsUnderflow)?;
+        self.set_pending_token(mint, amount);
+        Ok(())
+    }
+
+    /// Sets the pending token amount of the mint.
+    ///
+    /// The mint is dropped once nothing is pending, so that there
+    /// are no more mints than the queued transfers.
+    fn set_pending_token(&mut self, mint: &Pubkey, amount: u64) {
+        self.pending_tokens.retain(|pending| pending.mint != *mint);
+        if amount > 0 {
+            self.pending_tokens.push(PendingToken {
+                mint: *mint,
+                amount,
+            });
+        }
+    }
+
+    /// Validates the m-of-n quorum by the transaction signers.
+    ///
+    /// The signer is counted together with the co-signers passed
+    /// as the remaining accounts.  The accounts other than the
+    /// multisig signers with the [`Role::APPROVER`] role, or the ones
+    /// not signing the transaction, are simply ignored.
+    #[allow(clippy::result_large_err)]
+    fn validate_quorum(&self, signer: &Signer<'_>, accounts: &[AccountInfo<'_>]) -> Result<()> {
+        let mut signed = vec![false; self.signers.len()];
+        let cosigners = accounts.iter().filter(|account| account.is_signer);
+        for key in std::iter::once(signer.key).chain(cosigners.map(|account| account.key)) {
+            if let Some(index) = self.signers.iter().position(|pubkey| pubkey == key) {
+                signed[index] = self.has_role(index, Role::APPROVER);
+            }
+        }
+        require!(self.is_approved(&signed), Error::ThresholdNotMet);
+        Ok(())
     }
 
     /// Validates the multisig queue.
@@ -243,6 +516,14 @@ impl State {
         Ok(())
     }
 
+    /// Validates the queued account passed as the remaining account.
+    #[allow(clippy::result_large_err)]
+    fn validate_queued_account(account: &AccountInfo<'_>) -> Result<()> {
+        require!(account.is_writable, Error::InvalidQueuedAccount);
+        require_keys_eq!(*account.owner, id(), Error::InvalidQueuedAccount);
+        Ok(())
+    }
+
     /// Validates the multisig fund account.
     #[allow(clippy::result_large_err)]
     fn validate_fund<'info>(
@@ -267,6 +548,18 @@ impl State {
         Ok(())
     }
 
+    /// Validates the multisig fund keeps the rent-exempt minimum
+    /// and the lamports reserved by the queued transfers.
+    #[allow(clippy::result_large_err)]
+    fn validate_reserve(&self, fund: &UncheckedAccount<'_>) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(0);
+        let reserve = rent
+            .checked_add(self.pending)
+            .ok_or(Error::LamportsOverflow)?;
+        require_gte!(fund.lamports(), reserve, Error::FundBelowReserve);
+        Ok(())
+    }
+
     /// Creates a fund account.
     #[allow(clippy::result_large_err)]
     fn create_fund_account<'info>(
@@ -287,6 +580,25 @@ impl State {
         Ok(())
     }
 
+    /// Deposits lamports to the fund account.
+    #[allow(clippy::result_large_err)]
+    fn deposit_fund<'info>(
+        state: &mut Account<'info, Self>,
+        from: &Signer<'info>,
+        fund: &UncheckedAccount<'info>,
+        lamports: u64,
+    ) -> Result<()> {
+        // CPI to transfer fund to the multisig fund account.
+        let ix = system_instruction::transfer(&from.key(), &fund.key(), lamports);
+        let accounts = [from.to_account_info(), fund.to_account_info()];
+        invoke(&ix, &accounts)?;
+
+        // Update the balance.
+        state.add_balance(lamports)?;
+
+        Ok(())
+    }
+
     /// Withdraw fund.
     #[allow(clippy::result_large_err)]
     fn transfer_fund(
@@ -314,15 +626,104 @@ impl State {
             &[&seed],
         )?;
         */
-        **from.try_borrow_mut_lamports()? -= lamports;
-        **to.try_borrow_mut_lamports()? += lamports;
+        let from_lamports = from
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(Error::LamportsUnderflow)?;
+        **from.try_borrow_mut_lamports()? = from_lamports;
+        let to_lamports = to
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(Error::LamportsOverflow)?;
+        **to.try_borrow_mut_lamports()? = to_lamports;
 
         Ok(())
     }
+
+    /// Closes the queued account the same way as the Anchor `close`
+    /// constraint.
+    ///
+    /// All the lamports go to the multisig fund account, and the
+    /// account is given back to the system program with the empty
+    /// data, so that it can't be revived in the same transaction.
+    ///
+    /// It returns the lamports moved to the fund, which the caller
+    /// adds back to the [`State::balance`] when needed.
+    #[allow(clippy::result_large_err)]
+    fn close_account(
+        state: &Account<'_, Self>,
+        account: &AccountInfo<'_>,
+        fund: &AccountInfo<'_>,
+        bump: u8,
+    ) -> Result<u64> {
+        let lamports = account.lamports();
+        Self::transfer_fund(state, account, fund, lamports, bump)?;
+        account.assign(&System::id());
+        account.realloc(0, false)?;
+
+        Ok(lamports)
+    }
+}
+
+/// A token amount reserved by the pending token transfers of the mint.
+///
+/// It's kept in [`State::pending_tokens`], so that the queued token
+/// transfers don't exceed the multisig fund token balance.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PendingToken {
+    /// A token mint.
+    pub mint: Pubkey,
+
+    /// A token amount reserved by the pending token transfers.
+    pub amount: u64,
+}
+
+impl PendingToken {
+    const SPACE: usize = 32 + 8;
+}
+
+/// Roles of the multisig signer, stored as the bit flags in [`State::roles`].
+///
+/// All the roles are given to the signers by default, and
+/// can be changed by [`multisig_lite::set_roles`].
+pub struct Role;
+
+impl Role {
+    /// A role to create the transfers and the proposals.
+    pub const PROPOSER: u8 = 1 << 0;
+
+    /// A role to approve the transfers and the proposals.
+    pub const APPROVER: u8 = 1 << 1;
+
+    /// A role to execute the approved transfers and proposals.
+    pub const EXECUTOR: u8 = 1 << 2;
+
+    /// All the roles.
+    pub const ALL: u8 = Self::PROPOSER | Self::APPROVER | Self::EXECUTOR;
+}
+
+/// The SPL Memo program, recording the [`Transfer::memo`].
+#[derive(Clone)]
+pub struct Memo;
+
+impl Id for Memo {
+    fn id() -> Pubkey {
+        memo::ID
+    }
+}
+
+/// The SPL Memo program ID.
+pub mod memo {
+    use super::*;
+
+    declare_id!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 }
 
 /// A multisig [`Transfer`] account data.
 ///
+/// The account is a PDA of the multisig state and the index,
+/// which is the [`State::transfers`] at the creation.
+///
 /// # Examples
 ///
 /// Here is how to query the [`Transfer`] PDA account on Devnet.
@@ -347,15 +748,21 @@ impl State {
 /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
 ///
 /// // Gets the PDAs.
-/// let (state_pda, _state_bump) =
-///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
 ///
 /// // Query the `multisig_lite::State` account to get the queued transfers.
 /// let state: multisig_lite::State = program.account(state_pda)?;
 ///
 /// // Query the `multisig_lite::Transfer` accounts iteratively.
 /// for transfer in state.queue {
-///     let transfer: multisig_lite::Transfer = program.account(transfer)?;
+///     let transfer: multisig_lite::Transfer = match program.account(transfer) {
+///         Ok(transfer) => transfer,
+///         Err(_) => continue, // Skips the proposals.
+///     };
 ///     println!("{transfer:?}");
 /// }
 /// # Ok(())
@@ -372,165 +779,340 @@ pub struct Transfer {
     pub recipient: Pubkey,
 
     /// A lamports to transfer.
+    ///
+    /// It's the token amount in case of the token transfer.
     pub lamports: u64,
-}
-
-impl Transfer {
-    const SPACE: usize = 8 + 32 + 32 + 8;
-}
-
-/// Accounts for the [`multisig_lite::create`] instruction handler.
-///
-/// Please refer to the [`multisig_lite::create`] document for the example.
-#[derive(Accounts)]
-#[instruction(m: u8, signers: Vec<Pubkey>, q: u8, state_bump: u8, fund_bump: u8)]
-pub struct Create<'info> {
-    /// A funder of the multisig account.
-    #[account(mut)]
-    pub funder: Signer<'info>,
-
-    /// A multisig state PDA account.
-    #[account(
-        init,
-        payer = funder,
-        space = State::space(&signers, q),
-        seeds = [b"state", funder.key.as_ref()],
-        bump,
-    )]
-    pub state: Account<'info, State>,
 
-    /// A multisig fund account.
+    /// A token mint of the transfer.
     ///
-    /// CHECK: Checked by the [`multisig_lite::create`] instruction handler.
-    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
-    pub fund: UncheckedAccount<'info>,
-
-    /// The system program to create a multisig PDA accounts.
-    pub system_program: Program<'info, System>,
-}
+    /// It's `None` for the native SOL transfer.
+    pub mint: Option<Pubkey>,
 
-/// Accounts for the [`multisig_lite::fund`] instruction handler.
-///
-/// Please refer to the [`multisig_lite::fund`] document for the example.
-#[derive(Accounts)]
-#[instruction(lamports: u64, state_bump: u8, fund_bump: u8)]
-pub struct Fund<'info> {
-    /// A funder of the account.
+    /// An earliest execution time of the transfer, in unix timestamp.
     ///
-    /// The funding is only allowed by the multisig account creator.
-    #[account(mut)]
-    pub funder: Signer<'info>,
+    /// The approved transfer stays in the queue until then.
+    pub execute_after: Option<i64>,
 
-    /// A multisig state PDA account.
-    #[account(mut, seeds = [b"state", funder.key.as_ref()], bump = state_bump)]
-    pub state: Box<Account<'info, State>>,
+    /// An expiration time of the transfer, in unix timestamp.
+    ///
+    /// The expired transfer can't be approved nor executed, and
+    /// will be pruned by [`multisig_lite::prune_expired`].
+    pub expire_at: Option<i64>,
 
-    /// A multisig fund account.
+    /// A memo, e.g. the invoice reference, of the transfer.
     ///
-    /// CHECK: Checked by the [`multisig_lite::fund`] instruction handler.
-    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
-    pub fund: UncheckedAccount<'info>,
+    /// It's recorded through the SPL Memo program at the execution.
+    pub memo: Option<String>,
 
-    /// The system program to make the transfer of the fund.
-    pub system_program: Program<'info, System>,
+    /// A current signed state of the transfer.
+    ///
+    /// It's indexed by the position of the signer in
+    /// the multisig [`State::signers`] array.
+    pub signed: Vec<bool>,
 }
 
-/// Accounts for the [`multisig_lite::create_transfer`] instruction handler.
-///
-/// Please refer to the [`multisig_lite::create_transfer`] document for the example.
-#[derive(Accounts)]
-#[instruction(recipient: Pubkey, lamports: u64, fund_bump: u8)]
-pub struct CreateTransfer<'info> {
-    /// An initiator of the fund transfer.
-    ///
-    /// It should be one of the signers of the multisig account.
-    #[account(mut)]
-    pub creator: Signer<'info>,
+impl Transfer {
+    /// A maximum memo length in bytes.
+    pub const MAX_MEMO: usize = 128;
 
-    /// A multisig state PDA account.
-    #[account(mut)]
-    pub state: Box<Account<'info, State>>,
+    fn space(signers: &[Pubkey], memo: &Option<String>) -> usize {
+        let n = State::valid_n(signers.len() as u8) as usize;
+        let memo = memo.as_ref().map_or(0, |memo| 4 + memo.len());
+        8 + 32 + 32 + 8 + 1 + 32 + 1 + 8 + 1 + 8 + 1 + memo + 4 + n
+    }
 
-    /// A multisig fund PDA account.
-    ///
-    /// CHECK: Checked by the [`multisig_lite::create_transfer`] instruction handler.
-    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
-    pub fund: UncheckedAccount<'info>,
+    /// Records the memo with the multisig fund PDA signature.
+    #[allow(clippy::result_large_err)]
+    fn record_memo<'info>(
+        &self,
+        state: &Account<'info, State>,
+        fund: &AccountInfo<'info>,
+        memo_program: &AccountInfo<'info>,
+        bump: u8,
+    ) -> Result<()> {
+        let memo = match &self.memo {
+            None => return Ok(()),
+            Some(memo) => memo,
+        };
+        let ix = Instruction {
+            program_id: Memo::id(),
+            accounts: vec![AccountMeta::new_readonly(fund.key(), true)],
+            data: memo.as_bytes().to_vec(),
+        };
+        let state_key = state.key();
+        let seed = [b"fund", state_key.as_ref(), &[bump]];
+        invoke_signed(&ix, &[fund.clone(), memo_program.clone()], &[&seed])?;
+        Ok(())
+    }
 
-    /// A transfer account to keep the queued transfer info.
-    #[account(init, payer = creator, space = Transfer::SPACE)]
-    pub transfer: Box<Account<'info, Transfer>>,
+    /// Checks if the time lock of the transfer had been elapsed.
+    fn is_unlocked(&self, now: i64) -> bool {
+        match self.execute_after {
+            None => true,
+            Some(execute_after) => execute_after <= now,
+        }
+    }
 
-    /// The system program to create a transfer account.
-    pub system_program: Program<'info, System>,
+    /// Checks if the transfer had been expired.
+    fn is_expired(&self, now: i64) -> bool {
+        match self.expire_at {
+            None => false,
+            Some(expire_at) => expire_at <= now,
+        }
+    }
+
+    /// Validates if the transfer is ready to be executed.
+    #[allow(clippy::result_large_err)]
+    fn validate_ready(&self, state: &State, now: i64) -> Result<()> {
+        require!(!self.is_expired(now), Error::TransferExpired);
+        require!(state.is_approved(&self.signed), Error::ThresholdNotMet);
+        require!(self.is_unlocked(now), Error::TransferTimeLocked);
+        Ok(())
+    }
 }
 
-/// Accounts for the [`multisig_lite::approve`] instruction handler.
+/// A multisig [`Batch`] account data.
 ///
-/// Once one of the signer approves, the account is locked
-/// for the new transfer unless:
+/// It keeps the list of the native SOL payments, approved as one
+/// unit by the m number of signers and executed across one or more
+/// transactions by [`multisig_lite::execute_batch`].
 ///
-/// 1) Meets the m number of signers approval.
-/// 2) Closes the account.
+/// # Examples
 ///
-/// In case of the 1 above, the account will be unlocked
-/// and starts to take a new transfer again.
+/// Here is how to query the [`Batch`] accounts on Devnet.
 ///
-/// Please refer to the [`multisig_lite::approve`] document for the example.
-#[derive(Accounts)]
-#[instruction(fund_bump: u8)]
-pub struct Approve<'info> {
-    /// An approver of the current state of the multisg account.
-    #[account(mut)]
-    pub signer: Signer<'info>,
-
-    /// A multisig state PDA account.
-    #[account(mut)]
-    pub state: Box<Account<'info, State>>,
-
-    /// A multisig fund account.
-    ///
-    /// CHECK: Checked by the [`multisig_lite::approve`] instruction handler.
-    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
-    pub fund: UncheckedAccount<'info>,
-}
-
-/// Accounts for the [`multisig_lite::close`] instruction handler.
+/// ```no_run
+/// use std::rc::Rc;
 ///
-/// Please refer to the [`multisig_lite::close`] document for the example.
-#[derive(Accounts)]
-#[instruction(state_bump: u8, fund_bump: u8)]
-pub struct Close<'info> {
-    /// An original funder of the multisig account.
-    #[account(mut)]
-    pub funder: Signer<'info>,
-
-    /// A multisig state PDA account.
-    #[account(mut, close = funder, seeds = [b"state", funder.key.as_ref()], bump = state_bump)]
-    pub state: Box<Account<'info, State>>,
+/// use solana_sdk::commitment_config::CommitmentConfig;
+/// use solana_sdk::pubkey::Pubkey;
+/// use solana_sdk::signature::read_keypair_file;
+/// use solana_sdk::signer::Signer;
+///
+/// use anchor_client::{Client, Cluster};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = Cluster::Devnet;
+/// let funder = Rc::new(read_keypair_file(
+///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+/// )?);
+/// let opts = CommitmentConfig::processed();
+/// let pid = multisig_lite::id();
+/// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+///
+/// // Gets the PDAs.
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
+///
+/// // Query the `multisig_lite::State` account to get the queued batches.
+/// let state: multisig_lite::State = program.account(state_pda)?;
+///
+/// // Query the `multisig_lite::Batch` accounts iteratively.
+/// for batch in state.queue {
+///     let batch: multisig_lite::Batch = match program.account(batch) {
+///         Ok(batch) => batch,
+///         Err(_) => continue, // Skips the transfers and the proposals.
+///     };
+///     println!("{batch:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[account]
+#[derive(Debug)]
+pub struct Batch {
+    /// An creator of the batch, one of the multisig signers.
+    pub creator: Pubkey,
 
-    /// A multisig fund PDA account.
+    /// Payments of the batch.
+    pub payments: Vec<Payment>,
+
+    /// A number of the payments already executed.
     ///
-    /// CHECK: Checked by the [`multisig_lite::close`] instruction handler.
-    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
-    pub fund: UncheckedAccount<'info>,
+    /// The payments are executed in order, starting from this index.
+    pub executed: u8,
+
+    /// A current signed state of the batch.
+    ///
+    /// It's indexed by the position of the signer in
+    /// the multisig [`State::signers`] array.
+    pub signed: Vec<bool>,
 }
 
-/// Module representing the program instruction handlers.
+impl Batch {
+    /// A maximum payments of the batch, to fit in the account size limit.
+    const MAX_PAYMENTS: u8 = 200;
+
+    fn space(signers: &[Pubkey], payments: &[Payment]) -> usize {
+        let n = State::valid_n(signers.len() as u8) as usize;
+        8 + 32 + 4 + Payment::SPACE * payments.len() + 1 + 4 + n
+    }
+
+    /// Returns the total lamports of the payments.
+    #[allow(clippy::result_large_err)]
+    fn lamports(payments: &[Payment]) -> Result<u64> {
+        payments
+            .iter()
+            .try_fold(0u64, |sum, payment| sum.checked_add(payment.lamports))
+            .ok_or_else(|| Error::LamportsOverflow.into())
+    }
+
+    /// Checks if all the payments had been executed.
+    fn is_done(&self) -> bool {
+        self.executed as usize == self.payments.len()
+    }
+}
+
+/// A payment of the [`Batch`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Payment {
+    /// A recipient of the payment.
+    pub recipient: Pubkey,
+
+    /// A lamports to pay.
+    pub lamports: u64,
+}
+
+impl Payment {
+    const SPACE: usize = 32 + 8;
+}
+
+/// A multisig [`Proposal`] account data.
+///
+/// It keeps an arbitrary instruction, which will be executed
+/// with the multisig fund PDA signature once it meets the
+/// m number of signers approval.
 ///
 /// # Examples
 ///
-/// Here is how to approve pending transfers on Devnet.
+/// Here is how to query the [`Proposal`] accounts on Devnet.
 ///
-/// Please take a look at the individual functions for other
-/// instruction opperations. e.g. [`multisig_lite::fund`] for
-/// how to fund the multisig account.
+/// ```no_run
+/// use std::rc::Rc;
+///
+/// use solana_sdk::commitment_config::CommitmentConfig;
+/// use solana_sdk::pubkey::Pubkey;
+/// use solana_sdk::signature::read_keypair_file;
+/// use solana_sdk::signer::Signer;
+///
+/// use anchor_client::{Client, Cluster};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = Cluster::Devnet;
+/// let funder = Rc::new(read_keypair_file(
+///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+/// )?);
+/// let opts = CommitmentConfig::processed();
+/// let pid = multisig_lite::id();
+/// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+///
+/// // Gets the PDAs.
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
+///
+/// // Query the `multisig_lite::State` account to get the queued proposals.
+/// let state: multisig_lite::State = program.account(state_pda)?;
+///
+/// // Query the `multisig_lite::Proposal` accounts iteratively.
+/// for proposal in state.queue {
+///     let proposal: multisig_lite::Proposal = match program.account(proposal) {
+///         Ok(proposal) => proposal,
+///         Err(_) => continue, // Skips the transfers.
+///     };
+///     println!("{proposal:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[account]
+#[derive(Debug)]
+pub struct Proposal {
+    /// An creator of the proposal, one of the multisig
+    /// signers.
+    pub creator: Pubkey,
+
+    /// A program to execute the instruction.
+    pub program: Pubkey,
+
+    /// Accounts of the instruction.
+    pub keys: Vec<ProposalAccount>,
+
+    /// Data of the instruction.
+    pub data: Vec<u8>,
+
+    /// A current signed state of the proposal.
+    ///
+    /// It's indexed by the position of the signer in
+    /// the multisig [`State::signers`] array.
+    pub signed: Vec<bool>,
+}
+
+impl Proposal {
+    fn space(signers: &[Pubkey], keys: &[ProposalAccount], data: &[u8]) -> usize {
+        let n = State::valid_n(signers.len() as u8) as usize;
+        8 + 32 + 32 + 4 + ProposalAccount::SPACE * keys.len() + 4 + data.len() + 4 + n
+    }
+
+    /// Returns the instruction to execute.
+    fn instruction(&self) -> Instruction {
+        Instruction {
+            program_id: self.program,
+            accounts: self.keys.iter().map(AccountMeta::from).collect(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+/// An account of the [`Proposal`] instruction.
+///
+/// It mirrors [`AccountMeta`], which is not serializable by Anchor.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalAccount {
+    /// A public key of the account.
+    pub pubkey: Pubkey,
+
+    /// True if the instruction requires the signature of the account.
+    pub is_signer: bool,
+
+    /// True if the account data or the lamports may be mutated.
+    pub is_writable: bool,
+}
+
+impl ProposalAccount {
+    const SPACE: usize = 32 + 1 + 1;
+}
+
+impl From<&ProposalAccount> for AccountMeta {
+    fn from(account: &ProposalAccount) -> Self {
+        Self {
+            pubkey: account.pubkey,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }
+    }
+}
+
+/// A multisig [`SpendingLimit`] account data.
+///
+/// It allows the signer to transfer the lamports from the multisig
+/// fund up to the limit per period, without the m-of-n approval.
+/// The account is a PDA of the multisig state and the signer, and
+/// created and removed only by the m-of-n quorum.
+///
+/// # Examples
+///
+/// Here is how to query the [`SpendingLimit`] PDA account on Devnet.
 ///
 /// ```no_run
 /// use std::rc::Rc;
 ///
 /// use solana_sdk::commitment_config::CommitmentConfig;
-/// use solana_sdk::instruction::AccountMeta;
 /// use solana_sdk::pubkey::Pubkey;
 /// use solana_sdk::signature::read_keypair_file;
 /// use solana_sdk::signer::Signer;
@@ -547,65 +1129,4281 @@ pub struct Close<'info> {
 /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
 ///
 /// // Gets the PDAs.
-/// let (state_pda, state_bump) =
-///     Pubkey::find_program_address(&[b"state", signer.pubkey().as_ref()], &pid);
-/// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
+/// let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+///     &[b"limit", state_pda.as_ref(), signer.pubkey().as_ref()],
+///     &pid,
+/// );
 ///
-/// // Gets the pending transfers and the recipients account info.
-/// let mut remaining_accounts = vec![];
-/// let state: multisig_lite::State = program.account(state_pda)?;
-/// for transfer_pubkey in state.queue {
-///     let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;
-///
-///     // Pushes the transfer account.
-///     remaining_accounts.push(AccountMeta {
-///         pubkey: transfer_pubkey,
-///         is_signer: false,
-///         is_writable: true,
-///     });
-///
-///     // Pushes the recipient account.
-///     remaining_accounts.push(AccountMeta {
-///         pubkey: transfer.recipient,
-///         is_signer: false,
-///         is_writable: true,
-///     });
-/// }
+/// // Query the `multisig_lite::SpendingLimit` account.
+/// let limit: multisig_lite::SpendingLimit = program.account(limit_pda)?;
 ///
-/// // Approve the multisig account.
-/// let sig = program
-///     .request()
-///     .accounts(multisig_lite::accounts::Approve {
-///         signer: signer.pubkey(),
-///         state: state_pda,
-///         fund: fund_pda,
-///     })
-///     .args(multisig_lite::instruction::Approve { fund_bump })
-///     .accounts(remaining_accounts)
-///     .signer(signer.as_ref())
-///     .send()?;
-///
-/// println!("{sig}");
+/// println!("{limit:?}");
 /// # Ok(())
 /// # }
 /// ```
-#[program]
-pub mod multisig_lite {
-    use super::*;
+#[account]
+#[derive(Debug)]
+pub struct SpendingLimit {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer allowed to spend.
+    pub signer: Pubkey,
+
+    /// A maximum lamports to spend per period.
+    pub lamports: u64,
+
+    /// A period of the spending limit.
+    pub period: Period,
+
+    /// Lamports spent in the current period.
+    pub spent: u64,
+
+    /// A start of the current period, in unix timestamp.
+    pub period_start: i64,
+}
+
+impl SpendingLimit {
+    const SPACE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 8;
+
+    /// Resets the spent lamports when the current period is over.
+    fn refresh(&mut self, now: i64) {
+        let seconds = self.period.seconds();
+        let elapsed = now - self.period_start;
+        if elapsed >= seconds {
+            self.period_start += elapsed - elapsed % seconds;
+            self.spent = 0;
+        }
+    }
+
+    /// Returns the lamports still available in the current period.
+    fn available(&self) -> u64 {
+        self.lamports.saturating_sub(self.spent)
+    }
+}
+
+/// A period of the [`SpendingLimit`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Period {
+    /// The limit is reset every day.
+    Day,
+
+    /// The limit is reset every week.
+    Week,
+}
+
+impl Period {
+    /// Returns the length of the period in seconds.
+    fn seconds(&self) -> i64 {
+        match self {
+            Self::Day => 24 * 60 * 60,
+            Self::Week => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Emitted when a new multisig account is created.
+///
+/// Please refer to the [`multisig_lite::create`] document.
+#[event]
+pub struct MultisigCreated {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A funder of the multisig account.
+    pub funder: Pubkey,
+
+    /// A threshold of the multisig account.
+    pub m: u8,
+
+    /// Signers of the multisig account.
+    pub signers: Vec<Pubkey>,
+
+    /// Voting weights of the signers.
+    pub weights: Vec<u8>,
+
+    /// A maximum transfer queue length.
+    pub q: u8,
+}
+
+/// Emitted when the lamports are deposited to the multisig fund.
+///
+/// Please refer to the [`multisig_lite::fund`] and
+/// [`multisig_lite::deposit`] documents.
+#[event]
+pub struct Funded {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A funder of the lamports.
+    pub funder: Pubkey,
+
+    /// Deposited lamports.
+    pub lamports: u64,
+
+    /// A spendable balance after the deposit.
+    pub balance: u64,
+}
+
+/// Emitted when the fund balance is reconciled.
+///
+/// Please refer to the [`multisig_lite::sync_balance`] document.
+#[event]
+pub struct BalanceSynced {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A spendable balance after the reconciliation.
+    pub balance: u64,
+
+    /// Lamports reserved by the queued transfers.
+    pub pending: u64,
+}
+
+/// Emitted when a transfer is queued.
+///
+/// Please refer to the [`multisig_lite::create_transfer`] and
+/// [`multisig_lite::create_token_transfer`] documents.
+#[event]
+pub struct TransferCreated {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A queued transfer account.
+    pub transfer: Pubkey,
+
+    /// A creator of the transfer.
+    pub creator: Pubkey,
+
+    /// A recipient of the transfer.
+    pub recipient: Pubkey,
+
+    /// Lamports, or the token amount, to transfer.
+    pub lamports: u64,
+
+    /// A mint of the token transfer.
+    pub mint: Option<Pubkey>,
+
+    /// A memo of the transfer.
+    pub memo: Option<String>,
+
+    /// A queue length after the transfer is queued.
+    pub queue_len: u8,
+}
+
+/// Emitted when a transfer is cancelled.
+///
+/// Please refer to the [`multisig_lite::cancel_transfer`] document.
+#[event]
+pub struct TransferCancelled {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A cancelled transfer account.
+    pub transfer: Pubkey,
+
+    /// A signer cancelling the transfer.
+    pub signer: Pubkey,
+
+    /// A queue length after the transfer is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when an expired transfer is pruned.
+///
+/// Please refer to the [`multisig_lite::prune_expired`] document.
+#[event]
+pub struct TransferPruned {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A pruned transfer account.
+    pub transfer: Pubkey,
+
+    /// A queue length after the transfer is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when a transfer is approved by a signer.
+///
+/// Please refer to the [`multisig_lite::approve`] document.
+#[event]
+pub struct Approved {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// An approved transfer account.
+    pub transfer: Pubkey,
+
+    /// An approver of the transfer.
+    pub signer: Pubkey,
+
+    /// A summed weight of the approvals of the transfer.
+    pub approvals: u16,
+}
+
+/// Emitted when an approval of the transfer is revoked.
+///
+/// Please refer to the [`multisig_lite::revoke`] document.
+#[event]
+pub struct Revoked {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A transfer account.
+    pub transfer: Pubkey,
+
+    /// A signer revoking the approval.
+    pub signer: Pubkey,
+
+    /// A summed weight of the approvals of the transfer.
+    pub approvals: u16,
+}
+
+/// Emitted when a transfer is executed.
+///
+/// Please refer to the [`multisig_lite::execute`] and
+/// [`multisig_lite::execute_token`] documents.
+#[event]
+pub struct TransferExecuted {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// An executed transfer account.
+    pub transfer: Pubkey,
+
+    /// A recipient of the transfer.
+    pub recipient: Pubkey,
+
+    /// Lamports, or the token amount, transferred.
+    pub lamports: u64,
+
+    /// A mint of the token transfer.
+    pub mint: Option<Pubkey>,
+
+    /// A queue length after the transfer is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when a spending limit is created.
+///
+/// Please refer to the [`multisig_lite::create_spending_limit`] document.
+#[event]
+pub struct SpendingLimitCreated {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A signer allowed to spend.
+    pub pubkey: Pubkey,
+
+    /// A maximum lamports to spend per period.
+    pub lamports: u64,
+
+    /// A period of the spending limit.
+    pub period: Period,
+}
+
+/// Emitted when a spending limit is removed.
+///
+/// Please refer to the [`multisig_lite::remove_spending_limit`] document.
+#[event]
+pub struct SpendingLimitRemoved {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A signer no longer allowed to spend.
+    pub pubkey: Pubkey,
+}
+
+/// Emitted when the lamports are spent within the spending limit.
+///
+/// Please refer to the [`multisig_lite::spend`] document.
+#[event]
+pub struct Spent {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer spending the lamports.
+    pub signer: Pubkey,
+
+    /// A recipient of the lamports.
+    pub recipient: Pubkey,
+
+    /// Spent lamports.
+    pub lamports: u64,
+
+    /// Lamports spent in the current period.
+    pub spent: u64,
+}
+
+/// Emitted when a batch is queued.
+///
+/// Please refer to the [`multisig_lite::create_batch`] document.
+#[event]
+pub struct BatchCreated {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A created batch account.
+    pub batch: Pubkey,
+
+    /// A creator of the batch.
+    pub creator: Pubkey,
+
+    /// A number of the payments.
+    pub payments: u8,
+
+    /// Total lamports of the payments.
+    pub lamports: u64,
+
+    /// A queue length after the batch is queued.
+    pub queue_len: u8,
+}
+
+/// Emitted when a batch is cancelled.
+///
+/// Please refer to the [`multisig_lite::cancel_batch`] document.
+#[event]
+pub struct BatchCancelled {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A cancelled batch account.
+    pub batch: Pubkey,
+
+    /// A signer cancelling the batch.
+    pub signer: Pubkey,
+
+    /// Lamports of the unexecuted payments given back to the balance.
+    pub lamports: u64,
+
+    /// A queue length after the batch is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when a batch is approved by a signer.
+///
+/// Please refer to the [`multisig_lite::approve_batch`] document.
+#[event]
+pub struct BatchApproved {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// An approved batch account.
+    pub batch: Pubkey,
+
+    /// An approver of the batch.
+    pub signer: Pubkey,
+
+    /// A summed weight of the approvals of the batch.
+    pub approvals: u16,
+}
+
+/// Emitted when the payments of a batch are executed.
+///
+/// Please refer to the [`multisig_lite::execute_batch`] document.
+#[event]
+pub struct BatchExecuted {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A batch account.
+    pub batch: Pubkey,
+
+    /// Lamports paid by the execution.
+    pub lamports: u64,
+
+    /// A number of the payments executed so far.
+    pub executed: u8,
+
+    /// A queue length after the execution.
+    pub queue_len: u8,
+}
+
+/// Emitted when a proposal is queued.
+///
+/// Please refer to the [`multisig_lite::create_proposal`] document.
+#[event]
+pub struct ProposalCreated {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A queued proposal account.
+    pub proposal: Pubkey,
+
+    /// A creator of the proposal.
+    pub creator: Pubkey,
+
+    /// A program to execute the instruction.
+    pub program: Pubkey,
+
+    /// A queue length after the proposal is queued.
+    pub queue_len: u8,
+}
+
+/// Emitted when a proposal is cancelled.
+///
+/// Please refer to the [`multisig_lite::cancel_proposal`] document.
+#[event]
+pub struct ProposalCancelled {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A cancelled proposal account.
+    pub proposal: Pubkey,
+
+    /// A signer cancelling the proposal.
+    pub signer: Pubkey,
+
+    /// A queue length after the proposal is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when a proposal is approved by a signer.
+///
+/// Please refer to the [`multisig_lite::approve_proposal`] document.
+#[event]
+pub struct ProposalApproved {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// An approved proposal account.
+    pub proposal: Pubkey,
+
+    /// An approver of the proposal.
+    pub signer: Pubkey,
+
+    /// A summed weight of the approvals of the proposal.
+    pub approvals: u16,
+}
+
+/// Emitted when a proposal is executed.
+///
+/// Please refer to the [`multisig_lite::execute_proposal`] document.
+#[event]
+pub struct ProposalExecuted {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// An executed proposal account.
+    pub proposal: Pubkey,
+
+    /// A program executed the instruction.
+    pub program: Pubkey,
+
+    /// A queue length after the proposal is removed.
+    pub queue_len: u8,
+}
+
+/// Emitted when a signer is added.
+///
+/// Please refer to the [`multisig_lite::add_signer`] document.
+#[event]
+pub struct SignerAdded {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A new signer.
+    pub pubkey: Pubkey,
+}
+
+/// Emitted when a signer is removed.
+///
+/// Please refer to the [`multisig_lite::remove_signer`] document.
+#[event]
+pub struct SignerRemoved {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A removed signer.
+    pub pubkey: Pubkey,
+}
+
+/// Emitted when the threshold is changed.
+///
+/// Please refer to the [`multisig_lite::change_threshold`] document.
+#[event]
+pub struct ThresholdChanged {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A new threshold.
+    pub m: u8,
+}
+
+/// Emitted when the roles of the signer are changed.
+///
+/// Please refer to the [`multisig_lite::set_roles`] document.
+#[event]
+pub struct RolesChanged {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A signer of the new roles.
+    pub pubkey: Pubkey,
+
+    /// New [`Role`] flags.
+    pub roles: u8,
+}
+
+/// Emitted when the transfer queue is resized.
+///
+/// Please refer to the [`multisig_lite::resize_queue`] document.
+#[event]
+pub struct QueueResized {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer authorizing the change.
+    pub signer: Pubkey,
+
+    /// A new maximum transfer queue length.
+    pub q: u8,
+}
+
+/// Emitted when the multisig account is closed.
+///
+/// Please refer to the [`multisig_lite::close`] document.
+#[event]
+pub struct Closed {
+    /// A multisig state PDA account.
+    pub state: Pubkey,
+
+    /// A signer closing the multisig account.
+    pub signer: Pubkey,
+
+    /// A destination of the remaining fund.
+    pub destination: Pubkey,
+
+    /// Lamports transferred to the destination.
+    pub lamports: u64,
+}
+
+/// Accounts for the [`multisig_lite::create`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::create`] document for the example.
+#[derive(Accounts)]
+#[instruction(
+    m: u8,
+    signers: Vec<Pubkey>,
+    weights: Vec<u8>,
+    q: u8,
+    index: u64,
+    state_bump: u8,
+    fund_bump: u8,
+)]
+pub struct Create<'info> {
+    /// A funder of the multisig account.
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        init,
+        payer = funder,
+        space = State::space(signers.len(), q),
+        seeds = [b"state", funder.key.as_ref(), &index.to_le_bytes()],
+        bump,
+    )]
+    pub state: Account<'info, State>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::create`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// The system program to create a multisig PDA accounts.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::fund`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::fund`] document for the example.
+#[derive(Accounts)]
+#[instruction(lamports: u64, index: u64, state_bump: u8, fund_bump: u8)]
+pub struct Fund<'info> {
+    /// A funder of the account.
+    ///
+    /// The funding is only allowed by the multisig account creator.
+    /// Please use [`multisig_lite::deposit`] for other payers.
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        mut,
+        seeds = [b"state", funder.key.as_ref(), &index.to_le_bytes()],
+        bump = state_bump,
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::fund`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// The system program to make the transfer of the fund.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::deposit`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::deposit`] document for the example.
+#[derive(Accounts)]
+#[instruction(lamports: u64, fund_bump: u8)]
+pub struct Deposit<'info> {
+    /// A payer of the deposit.
+    ///
+    /// Any account can deposit to the multisig account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::deposit`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// The system program to make the transfer of the fund.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::sync_balance`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::sync_balance`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct SyncBalance<'info> {
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::sync_balance`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+}
+
+/// Accounts for the [`multisig_lite::create_transfer`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::create_transfer`] document for the example.
+#[derive(Accounts)]
+#[instruction(
+    recipient: Pubkey,
+    lamports: u64,
+    execute_after: Option<i64>,
+    expire_at: Option<i64>,
+    memo: Option<String>,
+    fund_bump: u8,
+)]
+pub struct CreateTransfer<'info> {
+    /// An initiator of the fund transfer.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::create_transfer`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A transfer PDA account to keep the queued transfer info.
+    #[account(
+        init,
+        payer = creator,
+        space = Transfer::space(&state.signers, &memo),
+        seeds = [b"transfer", state.key().as_ref(), &state.transfers.to_le_bytes()],
+        bump,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+
+    /// The system program to create a transfer account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::create_token_transfer`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::create_token_transfer`] document for the example.
+#[derive(Accounts)]
+#[instruction(
+    recipient: Pubkey,
+    amount: u64,
+    execute_after: Option<i64>,
+    expire_at: Option<i64>,
+    memo: Option<String>,
+    fund_bump: u8,
+)]
+pub struct CreateTokenTransfer<'info> {
+    /// An initiator of the token transfer.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::create_token_transfer`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A token mint of the transfer.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// A multisig fund associated token account of the mint.
+    #[account(associated_token::mint = mint, associated_token::authority = fund)]
+    pub fund_token: Box<Account<'info, TokenAccount>>,
+
+    /// A transfer PDA account to keep the queued transfer info.
+    #[account(
+        init,
+        payer = creator,
+        space = Transfer::space(&state.signers, &memo),
+        seeds = [b"transfer", state.key().as_ref(), &state.transfers.to_le_bytes()],
+        bump,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+
+    /// The system program to create a transfer account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::cancel_transfer`] instruction handler.
+///
+/// The transfer can be cancelled either by the creator of the
+/// transfer or by the m-of-n quorum.  In case of the later,
+/// other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::cancel_transfer`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct CancelTransfer<'info> {
+    /// A canceler of the transfer.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::cancel_transfer`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued transfer account to cancel.
+    ///
+    /// It's closed and the rent goes back to the multisig fund.
+    #[account(
+        mut,
+        close = fund,
+        constraint = state.queue.contains(&transfer.key()) @ Error::TransferNotQueued,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+}
+
+/// Accounts for the [`multisig_lite::approve`] instruction handler.
+///
+/// The approval is given to the specific [`Transfer`] account,
+/// which will be executable once it meets the m number of
+/// signers approval.  The other queued transfers are not affected.
+///
+/// Please refer to the [`multisig_lite::approve`] document for the example.
+#[derive(Accounts)]
+pub struct Approve<'info> {
+    /// An approver of the transfer.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A queued transfer account to approve.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&transfer.key()) @ Error::TransferNotQueued,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+}
+
+/// Accounts for the [`multisig_lite::execute`] instruction handler.
+///
+/// Only the signer with the [`Role::EXECUTOR`] role can execute
+/// the approved transfer.
+///
+/// Please refer to the [`multisig_lite::execute`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct Execute<'info> {
+    /// An executor of the multisig account.
+    pub executor: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::execute`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued transfer account to execute.
+    ///
+    /// The token transfer is executed by [`multisig_lite::execute_token`].
+    /// It's closed and the rent goes back to the multisig fund.
+    #[account(
+        mut,
+        close = fund,
+        constraint = state.queue.contains(&transfer.key()) @ Error::TransferNotQueued,
+        constraint = transfer.mint.is_none() @ Error::InvalidMint,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+
+    /// A recipient of the transfer.
+    ///
+    /// CHECK: Checked by the address constraint against the transfer account.
+    #[account(mut, address = transfer.recipient @ Error::InvalidRecipient)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// The SPL Memo program to record the transfer memo.
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Accounts for the [`multisig_lite::execute_token`] instruction handler.
+///
+/// Only the signer with the [`Role::EXECUTOR`] role can execute
+/// the approved token transfer.
+///
+/// Please refer to the [`multisig_lite::execute_token`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct ExecuteToken<'info> {
+    /// An executor of the multisig account.
+    pub executor: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::execute_token`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued token transfer account to execute.
+    ///
+    /// It's closed and the rent goes back to the multisig fund.
+    #[account(
+        mut,
+        close = fund,
+        constraint = state.queue.contains(&transfer.key()) @ Error::TransferNotQueued,
+        constraint = transfer.mint == Some(mint.key()) @ Error::InvalidMint,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+
+    /// A token mint of the transfer.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// A multisig fund associated token account of the mint.
+    #[account(mut, associated_token::mint = mint, associated_token::authority = fund)]
+    pub fund_token: Box<Account<'info, TokenAccount>>,
+
+    /// A recipient token account of the transfer.
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = recipient_token.owner == transfer.recipient @ Error::InvalidRecipient,
+    )]
+    pub recipient_token: Box<Account<'info, TokenAccount>>,
+
+    /// The token program to make the token transfer.
+    pub token_program: Program<'info, Token>,
+
+    /// The SPL Memo program to record the transfer memo.
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Accounts for the [`multisig_lite::prune_expired`] instruction handler.
+///
+/// The expired transfer accounts to prune should be passed as
+/// the writable remaining accounts.
+///
+/// Please refer to the [`multisig_lite::prune_expired`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct PruneExpired<'info> {
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::prune_expired`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+}
+
+/// Accounts for the [`multisig_lite::revoke`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::revoke`] document for the example.
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    /// A signer to revoke the approval.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A queued transfer account to revoke the approval.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&transfer.key()) @ Error::TransferNotQueued,
+    )]
+    pub transfer: Box<Account<'info, Transfer>>,
+}
+
+/// Accounts for the [`multisig_lite::create_spending_limit`] instruction handler.
+///
+/// The spending limit can be created only by the m-of-n quorum.
+/// Other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::create_spending_limit`] document for the example.
+#[derive(Accounts)]
+#[instruction(pubkey: Pubkey)]
+pub struct CreateSpendingLimit<'info> {
+    /// One of the signers of the multisig account.
+    ///
+    /// It pays the rent for the spending limit account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A spending limit PDA account of the signer.
+    #[account(
+        init,
+        payer = signer,
+        space = SpendingLimit::SPACE,
+        seeds = [b"limit", state.key().as_ref(), pubkey.as_ref()],
+        bump,
+    )]
+    pub limit: Box<Account<'info, SpendingLimit>>,
+
+    /// The system program to create a spending limit account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::remove_spending_limit`] instruction handler.
+///
+/// The spending limit can be removed only by the m-of-n quorum.
+/// Other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::remove_spending_limit`] document for the example.
+#[derive(Accounts)]
+pub struct RemoveSpendingLimit<'info> {
+    /// One of the signers of the multisig account.
+    ///
+    /// It gets back the rent of the spending limit account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A spending limit PDA account to remove.
+    #[account(mut, close = signer, has_one = state @ Error::InvalidSpendingLimit)]
+    pub limit: Box<Account<'info, SpendingLimit>>,
+}
+
+/// Accounts for the [`multisig_lite::spend`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::spend`] document for the example.
+#[derive(Accounts)]
+#[instruction(lamports: u64, fund_bump: u8)]
+pub struct Spend<'info> {
+    /// A signer allowed to spend by the spending limit.
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::spend`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A spending limit PDA account of the signer.
+    #[account(
+        mut,
+        has_one = state @ Error::InvalidSpendingLimit,
+        has_one = signer @ Error::InvalidSpendingLimit,
+    )]
+    pub limit: Box<Account<'info, SpendingLimit>>,
+
+    /// A recipient of the lamports.
+    ///
+    /// CHECK: Any account chosen by the signer within the limit.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+/// Accounts for the [`multisig_lite::create_batch`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::create_batch`] document for the example.
+#[derive(Accounts)]
+#[instruction(payments: Vec<Payment>, fund_bump: u8)]
+pub struct CreateBatch<'info> {
+    /// An initiator of the batch.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::create_batch`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A batch account to keep the queued payments.
+    #[account(
+        init,
+        payer = creator,
+        space = Batch::space(&state.signers, &payments),
+    )]
+    pub batch: Box<Account<'info, Batch>>,
+
+    /// The system program to create a batch account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::cancel_batch`] instruction handler.
+///
+/// The batch can be cancelled either by the creator of the
+/// batch or by the m-of-n quorum.  In case of the later,
+/// other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::cancel_batch`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct CancelBatch<'info> {
+    /// A canceler of the batch.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::cancel_batch`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued batch account to cancel.
+    ///
+    /// It's closed and the rent goes back to the multisig fund.
+    #[account(
+        mut,
+        close = fund,
+        constraint = state.queue.contains(&batch.key()) @ Error::BatchNotQueued,
+    )]
+    pub batch: Box<Account<'info, Batch>>,
+}
+
+/// Accounts for the [`multisig_lite::approve_batch`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::approve_batch`] document for the example.
+#[derive(Accounts)]
+pub struct ApproveBatch<'info> {
+    /// An approver of the batch.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A queued batch account to approve.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&batch.key()) @ Error::BatchNotQueued,
+    )]
+    pub batch: Box<Account<'info, Batch>>,
+}
+
+/// Accounts for the [`multisig_lite::execute_batch`] instruction handler.
+///
+/// The recipients of the next payments to execute should be passed
+/// as the writable remaining accounts, in the order of the payments.
+///
+/// Please refer to the [`multisig_lite::execute_batch`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct ExecuteBatch<'info> {
+    /// An executor of the multisig account.
+    pub executor: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::execute_batch`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued batch account to execute.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&batch.key()) @ Error::BatchNotQueued,
+    )]
+    pub batch: Box<Account<'info, Batch>>,
+}
+
+/// Accounts for the [`multisig_lite::create_proposal`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::create_proposal`] document for the example.
+#[derive(Accounts)]
+#[instruction(program: Pubkey, keys: Vec<ProposalAccount>, data: Vec<u8>, fund_bump: u8)]
+pub struct CreateProposal<'info> {
+    /// An initiator of the proposal.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::create_proposal`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A proposal account to keep the queued instruction.
+    #[account(
+        init,
+        payer = creator,
+        space = Proposal::space(&state.signers, &keys, &data),
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// The system program to create a proposal account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::cancel_proposal`] instruction handler.
+///
+/// The proposal can be cancelled either by the creator of the
+/// proposal or by the m-of-n quorum.  In case of the later,
+/// other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::cancel_proposal`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct CancelProposal<'info> {
+    /// A canceler of the proposal.
+    ///
+    /// It should be one of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::cancel_proposal`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued proposal account to cancel.
+    ///
+    /// It's closed and the rent goes back to the multisig fund.
+    #[account(
+        mut,
+        close = fund,
+        constraint = state.queue.contains(&proposal.key()) @ Error::ProposalNotQueued,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+/// Accounts for the [`multisig_lite::approve_proposal`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::approve_proposal`] document for the example.
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// An approver of the proposal.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    pub state: Box<Account<'info, State>>,
+
+    /// A queued proposal account to approve.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&proposal.key()) @ Error::ProposalNotQueued,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+/// Accounts for the [`multisig_lite::execute_proposal`] instruction handler.
+///
+/// The accounts of the proposal instruction, as well as the program
+/// to execute, should be passed as the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::execute_proposal`] document for the example.
+#[derive(Accounts)]
+#[instruction(fund_bump: u8)]
+pub struct ExecuteProposal<'info> {
+    /// An executor of the multisig account.
+    pub executor: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account, which signs the proposal instruction.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::execute_proposal`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A queued proposal account to execute.
+    #[account(
+        mut,
+        constraint = state.queue.contains(&proposal.key()) @ Error::ProposalNotQueued,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+/// Accounts for the [`multisig_lite::add_signer`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::add_signer`] document for the example.
+#[derive(Accounts)]
+pub struct AddSigner<'info> {
+    /// One of the signers of the multisig account.
+    ///
+    /// It pays the additional rent for the multisig state account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        mut,
+        realloc = State::space(state.signers.len() + 1, state.q),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The system program to pay the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::remove_signer`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::remove_signer`] document for the example.
+#[derive(Accounts)]
+pub struct RemoveSigner<'info> {
+    /// One of the signers of the multisig account.
+    ///
+    /// It receives the refunded rent of the multisig state account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        mut,
+        realloc = State::space(state.signers.len().saturating_sub(1), state.q),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The system program to realloc the state account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::change_threshold`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::change_threshold`] document for the example.
+#[derive(Accounts)]
+pub struct ChangeThreshold<'info> {
+    /// One of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Accounts for the [`multisig_lite::set_roles`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::set_roles`] document for the example.
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    /// One of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Accounts for the [`multisig_lite::resize_queue`] instruction handler.
+///
+/// Please refer to the [`multisig_lite::resize_queue`] document for the example.
+#[derive(Accounts)]
+#[instruction(q: u8)]
+pub struct ResizeQueue<'info> {
+    /// One of the signers of the multisig account.
+    ///
+    /// It pays the additional rent, or receives the refunded rent,
+    /// of the multisig state account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        mut,
+        realloc = State::space(state.signers.len(), q),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The system program to realloc the state account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`multisig_lite::close`] instruction handler.
+///
+/// The multisig account can be closed only by the m-of-n quorum.
+/// Other signers should sign the transaction and be passed as
+/// the remaining accounts.
+///
+/// Please refer to the [`multisig_lite::close`] document for the example.
+#[derive(Accounts)]
+#[instruction(index: u64, state_bump: u8, fund_bump: u8)]
+pub struct Close<'info> {
+    /// One of the signers of the multisig account.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// An original funder of the multisig account.
+    ///
+    /// It gets back the rent of the multisig state account.
+    ///
+    /// CHECK: Checked by the multisig state PDA seeds.
+    #[account(mut)]
+    pub funder: UncheckedAccount<'info>,
+
+    /// A multisig state PDA account.
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"state", funder.key.as_ref(), &index.to_le_bytes()],
+        bump = state_bump,
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// A multisig fund PDA account.
+    ///
+    /// CHECK: Checked by the [`multisig_lite::close`] instruction handler.
+    #[account(mut, seeds = [b"fund", state.key().as_ref()], bump = fund_bump)]
+    pub fund: UncheckedAccount<'info>,
+
+    /// A destination of the remaining fund.
+    ///
+    /// CHECK: Any account agreed by the m-of-n quorum.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// Module representing the program instruction handlers.
+///
+/// # Examples
+///
+/// Here is how to approve pending transfers on Devnet.
+///
+/// Please take a look at the individual functions for other
+/// instruction opperations. e.g. [`multisig_lite::fund`] for
+/// how to fund the multisig account.
+///
+/// ```no_run
+/// use std::rc::Rc;
+///
+/// use solana_sdk::commitment_config::CommitmentConfig;
+/// use solana_sdk::pubkey::Pubkey;
+/// use solana_sdk::signature::read_keypair_file;
+/// use solana_sdk::signer::Signer;
+///
+/// use anchor_client::{Client, Cluster};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = Cluster::Devnet;
+/// let signer = Rc::new(read_keypair_file(
+///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+/// )?);
+/// let opts = CommitmentConfig::processed();
+/// let pid = multisig_lite::id();
+/// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+///
+/// // Gets the PDAs.
+/// let index = 0u64; // the first multisig account of the funder.
+/// let (state_pda, _state_bump) = Pubkey::find_program_address(
+///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+///     &pid,
+/// );
+///
+/// // Approves the pending transfers one by one.
+/// let state: multisig_lite::State = program.account(state_pda)?;
+/// for transfer_pubkey in state.queue {
+///     if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {
+///         continue; // Skips the proposals.
+///     }
+///
+///     let sig = program
+///         .request()
+///         .accounts(multisig_lite::accounts::Approve {
+///             signer: signer.pubkey(),
+///             state: state_pda,
+///             transfer: transfer_pubkey,
+///         })
+///         .args(multisig_lite::instruction::Approve {})
+///         .signer(signer.as_ref())
+///         .send()?;
+///
+///     println!("{sig}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[program]
+pub mod multisig_lite {
+    use super::*;
+
+    /// Creates a multisig account.
+    ///
+    /// The funder can create multiple multisig accounts with the
+    /// different index, as both the funder Pubkey and the index
+    /// are used for the multisig PDA address generation.  Other
+    /// instructions, except [`multisig_lite::fund`] and
+    /// [`multisig_lite::close`], locate the multisig account by
+    /// the address.
+    ///
+    /// Each signer votes with the weight given at the same position
+    /// in the `weights` array, and the transfers and proposals are
+    /// approved once the summed weight of the approvals reaches the
+    /// threshold, `m`.  The threshold should be reachable by the
+    /// signers.
+    ///
+    /// The signers are kept in the given order, and the duplicate
+    /// signers are rejected.
+    ///
+    /// # Examples
+    ///
+    /// Here is how you create a multisig account on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Creates a multisig account.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::Create {
+    ///         funder: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::Create {
+    ///         m: 2, // m as in m/n.
+    ///         signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],
+    ///         weights: vec![2, 1, 1], // the funder's vote counts twice.
+    ///         q: 10, // transfer queue limit.
+    ///         _index: index,
+    ///         _state_bump: state_bump,
+    ///         fund_bump,
+    ///     })
+    ///     .signer(funder.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err, clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Context<Create>,
+        m: u8,
+        signers: Vec<Pubkey>,
+        weights: Vec<u8>,
+        q: u8,
+        _index: u64,
+        _state_bump: u8,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let funder = &mut ctx.accounts.funder;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+
+        // At least one signer is required.
+        require_gte!(m, State::MIN_SIGNERS, Error::NoSigners);
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Every signer should have a non-zero weight.
+        require_eq!(signers.len(), weights.len(), Error::InvalidWeights);
+        require!(!weights.contains(&0), Error::InvalidWeights);
+
+        // Checks the uniqueness of signer's address, while keeping
+        // the signers in the given order.
+        for (index, signer) in signers.iter().enumerate() {
+            require!(!signers[..index].contains(signer), Error::DuplicateSigner);
+        }
+        require_gte!(signers.len(), State::MIN_SIGNERS as usize, Error::NoSigners);
+        require_gte!(
+            State::MAX_SIGNERS as usize,
+            signers.len(),
+            Error::TooManySigners
+        );
+
+        // Checks if the threshold is reachable.
+        let threshold = m as usize;
+        let total_weight: usize = weights.iter().map(|weight| *weight as usize).sum();
+        require_gte!(total_weight, threshold, Error::ThresholdTooHigh);
+
+        // Creates a fund account.
+        State::create_fund_account(state, fund, funder, fund_bump)?;
+
+        // Initializes the multisig state account.
+        state.m = m;
+        state.signers = signers;
+        state.weights = weights;
+        state.roles = vec![Role::ALL; state.signers.len()];
+        state.fund = fund.key();
+        state.balance = 0;
+        state.pending = 0;
+        state.transfers = 0;
+        state.q = State::valid_q(q);
+
+        emit!(MultisigCreated {
+            state: state.key(),
+            funder: funder.key(),
+            m: state.m,
+            signers: state.signers.clone(),
+            weights: state.weights.clone(),
+            q: state.q,
+        });
+
+        Ok(())
+    }
+
+    /// Funds lamports to the multisig fund account.
+    ///
+    /// The funding is only allowed by the multisig account funder.
+    /// Other accounts can deposit through [`multisig_lite::deposit`].
+    ///
+    /// # Examples
+    ///
+    /// Here is how to fund to the multisig account on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Funds the multisig account.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::Fund {
+    ///         funder: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::Fund {
+    ///         lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)
+    ///         _index: index,
+    ///         _state_bump: state_bump,
+    ///         fund_bump,
+    ///     })
+    ///     .signer(funder.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn fund(
+        ctx: Context<Fund>,
+        lamports: u64,
+        _index: u64,
+        _state_bump: u8,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let funder = &ctx.accounts.funder;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Deposits the lamports to the multisig fund account.
+        State::deposit_fund(state, funder, fund, lamports)?;
+
+        emit!(Funded {
+            state: state.key(),
+            funder: funder.key(),
+            lamports,
+            balance: state.balance,
+        });
+
+        Ok(())
+    }
+
+    /// Deposits lamports to the multisig fund account.
+    ///
+    /// Unlike [`multisig_lite::fund`], any payer can deposit lamports
+    /// to the multisig account, located by the address.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to deposit to the multisig account on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let payer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, payer.clone(), opts).program(pid);
+    ///
+    /// // The multisig account to deposit, shared by the multisig signers.
+    /// let state_pda = Pubkey::new_unique();
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Deposits to the multisig account.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::Deposit {
+    ///         payer: payer.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::Deposit {
+    ///         lamports: 10 * LAMPORTS_PER_SOL,
+    ///         fund_bump,
+    ///     })
+    ///     .signer(payer.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn deposit(ctx: Context<Deposit>, lamports: u64, fund_bump: u8) -> Result<()> {
+        let payer = &ctx.accounts.payer;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Deposits the lamports to the multisig fund account.
+        State::deposit_fund(state, payer, fund, lamports)?;
+
+        emit!(Funded {
+            state: state.key(),
+            funder: payer.key(),
+            lamports,
+            balance: state.balance,
+        });
+
+        Ok(())
+    }
+
+    /// Synchronizes the multisig balance with the fund account lamports.
+    ///
+    /// The lamports directly transferred to the fund account, e.g.
+    /// by the system program transfer, are not accounted in the
+    /// multisig balance.  This permissionless instruction recomputes
+    /// the balance as the fund account lamports minus the rent-exempt
+    /// minimum and the lamports reserved by the pending transfers.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to synchronize the multisig balance on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let payer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, payer.clone(), opts).program(pid);
+    ///
+    /// // The multisig account to synchronize.
+    /// let state_pda = Pubkey::new_unique();
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Synchronizes the multisig balance.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::SyncBalance {
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///     })
+    ///     .args(multisig_lite::instruction::SyncBalance { fund_bump })
+    ///     .signer(payer.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn sync_balance(ctx: Context<SyncBalance>, fund_bump: u8) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Recomputes the spendable balance.
+        let rent = Rent::get()?.minimum_balance(0);
+        state.balance = fund
+            .lamports()
+            .saturating_sub(rent)
+            .saturating_sub(state.pending);
+
+        emit!(BalanceSynced {
+            state: state.key(),
+            balance: state.balance,
+            pending: state.pending,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a queued transfer lamports to the recipient.
+    ///
+    /// The transfer account is a PDA of the multisig state and the
+    /// [`State::transfers`] index, and its creation fee will be given
+    /// back to the creator of the transfer from the multisig fund.
+    /// The [`State::balance`] should cover both the transfer lamports
+    /// and the creation fee, which goes back to the balance once the
+    /// transfer account is closed.
+    /// The optional `execute_after` unix timestamp time-locks the
+    /// transfer execution, so that the signers can notice and
+    /// cancel the suspicious transfer.  The optional `expire_at`
+    /// unix timestamp voids the transfer after the deadline.
+    /// The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, tells
+    /// what the transfer is for and is recorded through the SPL Memo
+    /// program at the execution.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to create a pending transfer on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Gets the next transfer PDA.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(
+    ///     &[b"transfer", state_pda.as_ref(), &state.transfers.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Creates a pending transfer.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CreateTransfer {
+    ///         creator: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         transfer: transfer_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::CreateTransfer {
+    ///         recipient: Pubkey::new_unique(),
+    ///         lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)
+    ///         execute_after: None,
+    ///         expire_at: None,
+    ///         memo: Some("Invoice #1234".to_string()),
+    ///         fund_bump,
+    ///     })
+    ///     .signer(funder.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_transfer(
+        ctx: Context<CreateTransfer>,
+        recipient: Pubkey,
+        lamports: u64,
+        execute_after: Option<i64>,
+        expire_at: Option<i64>,
+        memo: Option<String>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let transfer = &mut ctx.accounts.transfer;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the memo length.
+        if let Some(memo) = &memo {
+            require_gte!(Transfer::MAX_MEMO, memo.len(), Error::MemoTooLong);
+        }
+
+        // Checks the creator.
+        let creator_key = creator.key();
+        let creator_index = state.signer_index(&creator_key)?;
+        require!(
+            state.has_role(creator_index, Role::PROPOSER),
+            Error::NotProposer
+        );
+
+        // Check the current transfer queue.
+        state.validate_queue()?;
+
+        // Checks the multisig fund balance, which covers both the
+        // transfer and the rent fee given back to the creator.
+        let rent = transfer.to_account_info().lamports();
+        let total = lamports.checked_add(rent).ok_or(Error::LamportsOverflow)?;
+        require_gte!(state.balance, total, Error::NotEnoughFundBalance);
+
+        // Giving back the rent fee to the creator.
+        let from = fund.to_account_info();
+        let to = creator.to_account_info();
+        State::transfer_fund(state, &from, &to, rent, fund_bump)?;
+        state.sub_balance(rent)?;
+
+        // Initializes the transfer account, and
+        // queue it under multisig account for the
+        // future transfer execution.
+        transfer.creator = creator_key;
+        transfer.recipient = recipient;
+        transfer.lamports = lamports;
+        transfer.mint = None;
+        transfer.execute_after = execute_after;
+        transfer.expire_at = expire_at;
+        transfer.memo = memo;
+        transfer.signed = vec![false; state.signers.len()];
+        state.sub_balance(lamports)?;
+        state.add_pending(lamports)?;
+        state.transfers += 1;
+        state.queue.push(transfer.key());
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(TransferCreated {
+            state: state.key(),
+            transfer: transfer.key(),
+            creator: creator_key,
+            recipient,
+            lamports,
+            mint: None,
+            memo: transfer.memo.clone(),
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a queued token transfer to the recipient.
+    ///
+    /// The tokens are transferred from the multisig fund associated
+    /// token account of the mint, which should be created before
+    /// through the associated token account program.  The token
+    /// balance should cover the amount on top of the other pending
+    /// token transfers of the mint, kept in [`State::pending_tokens`].
+    /// The transfer account is a PDA of the multisig state and the
+    /// [`State::transfers`] index, and its creation fee will be given
+    /// back to the creator of the transfer from the multisig fund.
+    /// The optional `memo`, up to [`Transfer::MAX_MEMO`] bytes, is
+    /// recorded through the SPL Memo program at the execution, as
+    /// [`multisig_lite::create_transfer`] does.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to create a pending token transfer on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    /// use anchor_spl::associated_token::get_associated_token_address;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // The token mint and the fund associated token account.
+    /// let mint = Pubkey::new_unique();
+    /// let fund_token = get_associated_token_address(&fund_pda, &mint);
+    ///
+    /// // Gets the next transfer PDA.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let (transfer_pda, _transfer_bump) = Pubkey::find_program_address(
+    ///     &[b"transfer", state_pda.as_ref(), &state.transfers.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Creates a pending token transfer.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CreateTokenTransfer {
+    ///         creator: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         mint,
+    ///         fund_token,
+    ///         transfer: transfer_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::CreateTokenTransfer {
+    ///         recipient: Pubkey::new_unique(),
+    ///         amount: 1_000,
+    ///         execute_after: None,
+    ///         expire_at: None,
+    ///         memo: Some("Invoice #1234".to_string()),
+    ///         fund_bump,
+    ///     })
+    ///     .signer(funder.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_token_transfer(
+        ctx: Context<CreateTokenTransfer>,
+        recipient: Pubkey,
+        amount: u64,
+        execute_after: Option<i64>,
+        expire_at: Option<i64>,
+        memo: Option<String>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let mint = &ctx.accounts.mint;
+        let fund_token = &ctx.accounts.fund_token;
+        let transfer = &mut ctx.accounts.transfer;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the memo length.
+        if let Some(memo) = &memo {
+            require_gte!(Transfer::MAX_MEMO, memo.len(), Error::MemoTooLong);
+        }
+
+        // Checks the creator.
+        let creator_key = creator.key();
+        let creator_index = state.signer_index(&creator_key)?;
+        require!(
+            state.has_role(creator_index, Role::PROPOSER),
+            Error::NotProposer
+        );
+
+        // Check the current transfer queue.
+        state.validate_queue()?;
+
+        // Checks the multisig token balance, which covers the
+        // pending token transfers of the mint, too.
+        let mint_key = mint.key();
+        let pending = state
+            .pending_token(&mint_key)
+            .checked_add(amount)
+            .ok_or(Error::LamportsOverflow)?;
+        require_gte!(fund_token.amount, pending, Error::NotEnoughFundBalance);
+
+        // Checks the multisig fund balance for the rent fee.
+        let rent = transfer.to_account_info().lamports();
+        require_gte!(state.balance, rent, Error::NotEnoughFundBalance);
+
+        // Giving back the rent fee to the creator.
+        let from = fund.to_account_info();
+        let to = creator.to_account_info();
+        State::transfer_fund(state, &from, &to, rent, fund_bump)?;
+        state.sub_balance(rent)?;
+
+        // Initializes the token transfer account, and
+        // queue it under multisig account for the
+        // future transfer execution.
+        transfer.creator = creator_key;
+        transfer.recipient = recipient;
+        transfer.lamports = amount;
+        transfer.mint = Some(mint_key);
+        transfer.execute_after = execute_after;
+        transfer.expire_at = expire_at;
+        transfer.memo = memo;
+        transfer.signed = vec![false; state.signers.len()];
+        state.add_pending_token(&mint_key, amount)?;
+        state.transfers += 1;
+        state.queue.push(transfer.key());
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(TransferCreated {
+            state: state.key(),
+            transfer: transfer.key(),
+            creator: creator_key,
+            recipient,
+            lamports: amount,
+            mint: transfer.mint,
+            memo: transfer.memo.clone(),
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels a queued transfer.
+    ///
+    /// It gives the transfer lamports back to the multisig balance
+    /// and closes the transfer account.  The creator of the transfer
+    /// can cancel it by itself.  Otherwise, it requires the m-of-n
+    /// quorum, passed as the signer remaining accounts.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to cancel the first pending transfer on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let creator = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, creator.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", creator.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Gets the first pending transfer.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let transfer = state.queue[0];
+    ///
+    /// // Cancels the transfer.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CancelTransfer {
+    ///         signer: creator.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         transfer,
+    ///     })
+    ///     .args(multisig_lite::instruction::CancelTransfer { fund_bump })
+    ///     .signer(creator.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>, fund_bump: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let transfer = &mut ctx.accounts.transfer;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the signer.
+        let signer_key = signer.key();
+        state.signer_index(&signer_key)?;
+
+        // Checks the m-of-n quorum unless it's cancelled by the creator.
+        if transfer.creator != signer_key {
+            state.validate_quorum(signer, ctx.remaining_accounts)?;
+        }
+
+        // Gives back the reserved lamports and update the queue.
+        let transfer_key = transfer.key();
+        match &transfer.mint {
+            None => {
+                state.add_balance(transfer.lamports)?;
+                state.sub_pending(transfer.lamports)?;
+            }
+            Some(mint) => state.sub_pending_token(mint, transfer.lamports)?,
+        }
+        state.queue.retain(|pubkey| *pubkey != transfer_key);
+
+        // The rent fee goes back to the fund by the close constraint.
+        state.add_balance(transfer.to_account_info().lamports())?;
+
+        emit!(TransferCancelled {
+            state: state.key(),
+            transfer: transfer_key,
+            signer: signer_key,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Approves the queued transfer.
+    ///
+    /// It only records the approval of the signer.  The transfer is
+    /// executed by [`multisig_lite::execute`], or by
+    /// [`multisig_lite::execute_token`] for the token transfer,
+    /// once it meets the m number of signers approval.
+    /// The expired transfer can't be approved and will be pruned
+    /// by [`multisig_lite::prune_expired`].
+    ///
+    /// Approving the same transfer again is a no-op.  The transfer
+    /// not in the multisig queue is rejected with
+    /// [`Error::TransferNotQueued`], instead of being silently skipped.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to approve pending transfers on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Approves the pending transfers one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for transfer_pubkey in state.queue {
+    ///     if program.account::<multisig_lite::Transfer>(transfer_pubkey).is_err() {
+    ///         continue; // Skips the proposals.
+    ///     }
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::Approve {
+    ///             signer: signer.pubkey(),
+    ///             state: state_pda,
+    ///             transfer: transfer_pubkey,
+    ///         })
+    ///         .args(multisig_lite::instruction::Approve {})
+    ///         .signer(signer.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn approve(ctx: Context<Approve>) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let transfer = &mut ctx.accounts.transfer;
+
+        // Checks the signer.
+        let signer_index = state.signer_index(&signer.key())?;
+        require!(
+            state.has_role(signer_index, Role::APPROVER),
+            Error::NotApprover
+        );
+
+        // Checks the expiration.
+        let now = Clock::get()?.unix_timestamp;
+        require!(!transfer.is_expired(now), Error::TransferExpired);
+
+        // Records the approval.
+        transfer.signed[signer_index] = true;
+
+        emit!(Approved {
+            state: state.key(),
+            transfer: transfer.key(),
+            signer: signer.key(),
+            approvals: state.approvals(&transfer.signed) as u16,
+        });
+
+        Ok(())
+    }
+
+    /// Executes the approved transfer.
+    ///
+    /// The signer with the [`Role::EXECUTOR`] role can execute the
+    /// transfer once it meets the m number of signers approval, which
+    /// makes it possible to batch the execution by an executor bot.
+    /// The time-locked transfer can't be executed until the
+    /// `execute_after` timestamp.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to execute the approved transfers on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let executor = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, executor.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", executor.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Executes the approved transfers one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for transfer_pubkey in &state.queue {
+    ///     let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {
+    ///         Ok(transfer) => transfer,
+    ///         Err(_) => continue, // Skips the proposals.
+    ///     };
+    ///     let approvals: usize = transfer
+    ///         .signed
+    ///         .iter()
+    ///         .zip(&state.weights)
+    ///         .filter(|(signed, _)| **signed)
+    ///         .map(|(_, weight)| *weight as usize)
+    ///         .sum();
+    ///     if transfer.mint.is_some() || approvals < state.m as usize {
+    ///         continue;
+    ///     }
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::Execute {
+    ///             executor: executor.pubkey(),
+    ///             state: state_pda,
+    ///             fund: fund_pda,
+    ///             transfer: *transfer_pubkey,
+    ///             recipient: transfer.recipient,
+    ///             memo_program: multisig_lite::memo::ID,
+    ///         })
+    ///         .args(multisig_lite::instruction::Execute { fund_bump })
+    ///         .signer(executor.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn execute(ctx: Context<Execute>, fund_bump: u8) -> Result<()> {
+        let executor = &ctx.accounts.executor;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let transfer = &mut ctx.accounts.transfer;
+        let recipient = &mut ctx.accounts.recipient;
+        let memo_program = &ctx.accounts.memo_program;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the executor.
+        let executor_index = state.signer_index(&executor.key())?;
+        require!(
+            state.has_role(executor_index, Role::EXECUTOR),
+            Error::NotExecutor
+        );
+
+        // Checks if the transfer is ready.
+        transfer.validate_ready(state, Clock::get()?.unix_timestamp)?;
+
+        // Records the memo, if any, for the explorers.
+        let from = fund.to_account_info();
+        let memo_program = memo_program.to_account_info();
+        transfer.record_memo(state, &from, &memo_program, fund_bump)?;
+
+        // Fund to the recipient.
+        let to = recipient.to_account_info();
+        State::transfer_fund(state, &from, &to, transfer.lamports, fund_bump)?;
+
+        // Update the pending lamports and the queue.
+        let transfer_key = transfer.key();
+        state.sub_pending(transfer.lamports)?;
+        state.queue.retain(|pubkey| *pubkey != transfer_key);
+
+        // The rent fee goes back to the fund by the close constraint.
+        state.add_balance(transfer.to_account_info().lamports())?;
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(TransferExecuted {
+            state: state.key(),
+            transfer: transfer_key,
+            recipient: recipient.key(),
+            lamports: transfer.lamports,
+            mint: None,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Executes the approved token transfer.
+    ///
+    /// The tokens are transferred from the multisig fund associated
+    /// token account to the recipient token account, signed by the
+    /// multisig fund PDA.  The signer with the [`Role::EXECUTOR`] role
+    /// can execute the token transfer once it meets the m number of
+    /// signers approval.  The memo of the transfer, if any, is recorded
+    /// through the SPL Memo program.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to execute the approved token transfers on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    /// use anchor_spl::associated_token::get_associated_token_address;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let executor = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, executor.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", executor.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Executes the approved token transfers one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for transfer_pubkey in &state.queue {
+    ///     let transfer: multisig_lite::Transfer = match program.account(*transfer_pubkey) {
+    ///         Ok(transfer) => transfer,
+    ///         Err(_) => continue, // Skips the proposals.
+    ///     };
+    ///     let mint = match transfer.mint {
+    ///         Some(mint) => mint,
+    ///         None => continue,
+    ///     };
+    ///     let approvals: usize = transfer
+    ///         .signed
+    ///         .iter()
+    ///         .zip(&state.weights)
+    ///         .filter(|(signed, _)| **signed)
+    ///         .map(|(_, weight)| *weight as usize)
+    ///         .sum();
+    ///     if approvals < state.m as usize {
+    ///         continue;
+    ///     }
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::ExecuteToken {
+    ///             executor: executor.pubkey(),
+    ///             state: state_pda,
+    ///             fund: fund_pda,
+    ///             transfer: *transfer_pubkey,
+    ///             mint,
+    ///             fund_token: get_associated_token_address(&fund_pda, &mint),
+    ///             recipient_token: get_associated_token_address(&transfer.recipient, &mint),
+    ///             token_program: anchor_spl::token::ID,
+    ///             memo_program: multisig_lite::memo::ID,
+    ///         })
+    ///         .args(multisig_lite::instruction::ExecuteToken { fund_bump })
+    ///         .signer(executor.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn execute_token(ctx: Context<ExecuteToken>, fund_bump: u8) -> Result<()> {
+        let executor = &ctx.accounts.executor;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let transfer = &mut ctx.accounts.transfer;
+        let fund_token = &ctx.accounts.fund_token;
+        let recipient_token = &ctx.accounts.recipient_token;
+        let token_program = &ctx.accounts.token_program;
+        let memo_program = &ctx.accounts.memo_program;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the executor.
+        let executor_index = state.signer_index(&executor.key())?;
+        require!(
+            state.has_role(executor_index, Role::EXECUTOR),
+            Error::NotExecutor
+        );
+
+        // Checks if the transfer is ready.
+        transfer.validate_ready(state, Clock::get()?.unix_timestamp)?;
+
+        // Records the memo, if any, for the explorers.
+        let from = fund.to_account_info();
+        let memo_program = memo_program.to_account_info();
+        transfer.record_memo(state, &from, &memo_program, fund_bump)?;
+
+        // CPI to transfer the tokens to the recipient, signed by the fund PDA.
+        let state_key = state.key();
+        let seed = [b"fund", state_key.as_ref(), &[fund_bump]];
+        let signer_seeds = [&seed[..]];
+        let accounts = token::Transfer {
+            from: fund_token.to_account_info(),
+            to: recipient_token.to_account_info(),
+            authority: fund.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(token_program.to_account_info(), accounts, &signer_seeds);
+        token::transfer(cpi_ctx, transfer.lamports)?;
+
+        // Update the pending tokens and the queue.
+        let transfer_key = transfer.key();
+        if let Some(mint) = &transfer.mint {
+            state.sub_pending_token(mint, transfer.lamports)?;
+        }
+        state.queue.retain(|pubkey| *pubkey != transfer_key);
+
+        // The rent fee goes back to the fund by the close constraint.
+        state.add_balance(transfer.to_account_info().lamports())?;
+
+        emit!(TransferExecuted {
+            state: state_key,
+            transfer: transfer_key,
+            recipient: transfer.recipient,
+            lamports: transfer.lamports,
+            mint: transfer.mint,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Prunes the expired transfers.
+    ///
+    /// Anyone can prune the expired transfers, passed as the writable
+    /// remaining accounts.  It closes the transfer accounts by giving
+    /// back the rent fee to the multisig fund, and restores the
+    /// reserved lamports to the multisig balance.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to prune the expired transfers on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let payer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, payer.clone(), opts).program(pid);
+    ///
+    /// // The multisig account to prune the expired transfers.
+    /// let state_pda = Pubkey::new_unique();
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Gets the expired transfers.
+    /// let now = std::time::SystemTime::now()
+    ///     .duration_since(std::time::UNIX_EPOCH)?
+    ///     .as_secs() as i64;
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let mut remaining_accounts = vec![];
+    /// for transfer_pubkey in state.queue {
+    ///     let transfer: multisig_lite::Transfer = match program.account(transfer_pubkey) {
+    ///         Ok(transfer) => transfer,
+    ///         Err(_) => continue, // Skips the proposals.
+    ///     };
+    ///     if transfer.expire_at.map(|expire_at| expire_at <= now) == Some(true) {
+    ///         remaining_accounts.push(AccountMeta::new(transfer_pubkey, false));
+    ///     }
+    /// }
+    ///
+    /// // Prunes the expired transfers.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::PruneExpired {
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///     })
+    ///     .args(multisig_lite::instruction::PruneExpired { fund_bump })
+    ///     .accounts(remaining_accounts)
+    ///     .signer(payer.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn prune_expired<'info>(
+        ctx: Context<'_, '_, '_, 'info, PruneExpired<'info>>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let to = fund.to_account_info();
+        for account in ctx.remaining_accounts {
+            // Checks the transfer account.
+            require!(state.queue.contains(account.key), Error::TransferNotQueued);
+            State::validate_queued_account(account)?;
+            let transfer = Account::<Transfer>::try_from(account)?;
+            require!(transfer.is_expired(now), Error::TransferNotExpired);
+
+            // Closes the transfer account by transfering the
+            // rent fee back to the fund account.
+            let rent = State::close_account(state, account, &to, fund_bump)?;
+            state.add_balance(rent)?;
+
+            // Gives back the reserved lamports and update the queue.
+            match &transfer.mint {
+                None => {
+                    state.add_balance(transfer.lamports)?;
+                    state.sub_pending(transfer.lamports)?;
+                }
+                Some(mint) => state.sub_pending_token(mint, transfer.lamports)?,
+            }
+            state.queue.retain(|pubkey| pubkey != account.key);
+
+            emit!(TransferPruned {
+                state: state.key(),
+                transfer: account.key(),
+                queue_len: state.queue.len() as u8,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Revokes the approval of the queued transfer.
+    ///
+    /// The approval can be revoked only before the transfer
+    /// execution.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to revoke the approval of the pending transfers on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Revokes the approval of the first pending transfer.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::Revoke {
+    ///         signer: signer.pubkey(),
+    ///         state: state_pda,
+    ///         transfer: state.queue[0],
+    ///     })
+    ///     .args(multisig_lite::instruction::Revoke {})
+    ///     .signer(signer.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let transfer = &mut ctx.accounts.transfer;
+
+        // Checks the signer.
+        let signer_index = state.signer_index(&signer.key())?;
+
+        // Nothing to revoke.
+        require!(transfer.signed[signer_index], Error::NotApproved);
+
+        transfer.signed[signer_index] = false;
+
+        emit!(Revoked {
+            state: state.key(),
+            transfer: transfer.key(),
+            signer: signer.key(),
+            approvals: state.approvals(&transfer.signed) as u16,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a spending limit of the signer.
+    ///
+    /// The signer can transfer the lamports from the multisig fund
+    /// up to the limit per day or week with [`multisig_lite::spend`],
+    /// without queuing the transfer.  It requires the m-of-n quorum,
+    /// passed as the signer remaining accounts.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to allow a signer to spend 1 SOL per day on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let spender = Pubkey::new_unique(); // one of the multisig signers.
+    /// let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+    ///     &[b"limit", state_pda.as_ref(), spender.as_ref()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Creates a spending limit.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CreateSpendingLimit {
+    ///         signer: signer.pubkey(),
+    ///         state: state_pda,
+    ///         limit: limit_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::CreateSpendingLimit {
+    ///         pubkey: spender,
+    ///         lamports: 1_000_000_000,
+    ///         period: multisig_lite::Period::Day,
+    ///     })
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_spending_limit(
+        ctx: Context<CreateSpendingLimit>,
+        pubkey: Pubkey,
+        lamports: u64,
+        period: Period,
+    ) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let limit = &mut ctx.accounts.limit;
+
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
+
+        // Checks the spender.
+        state.signer_index(&pubkey)?;
+
+        // Initializes the spending limit account.
+        limit.state = state.key();
+        limit.signer = pubkey;
+        limit.lamports = lamports;
+        limit.period = period;
+        limit.spent = 0;
+        limit.period_start = Clock::get()?.unix_timestamp;
+
+        emit!(SpendingLimitCreated {
+            state: state.key(),
+            signer: signer.key(),
+            pubkey,
+            lamports,
+            period,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the spending limit of the signer.
+    ///
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts.  The rent of the spending limit account goes back to
+    /// the signer.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to remove the spending limit on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let spender = Pubkey::new_unique(); // one of the multisig signers.
+    /// let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+    ///     &[b"limit", state_pda.as_ref(), spender.as_ref()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Removes the spending limit.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::RemoveSpendingLimit {
+    ///         signer: signer.pubkey(),
+    ///         state: state_pda,
+    ///         limit: limit_pda,
+    ///     })
+    ///     .args(multisig_lite::instruction::RemoveSpendingLimit {})
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn remove_spending_limit(ctx: Context<RemoveSpendingLimit>) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let limit = &ctx.accounts.limit;
+
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
+
+        emit!(SpendingLimitRemoved {
+            state: state.key(),
+            signer: signer.key(),
+            pubkey: limit.signer,
+        });
+
+        Ok(())
+    }
+
+    /// Spends the lamports within the spending limit of the signer.
+    ///
+    /// The lamports are transferred from the multisig fund to the
+    /// recipient right away, without queuing the transfer.  The spent
+    /// lamports are reset at the beginning of every period.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to spend 0.1 SOL within the spending limit on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    /// let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+    ///     &[b"limit", state_pda.as_ref(), signer.pubkey().as_ref()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Spends the lamports.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::Spend {
+    ///         signer: signer.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         limit: limit_pda,
+    ///         recipient: Pubkey::new_unique(),
+    ///     })
+    ///     .args(multisig_lite::instruction::Spend {
+    ///         lamports: 100_000_000,
+    ///         fund_bump,
+    ///     })
+    ///     .signer(signer.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn spend(ctx: Context<Spend>, lamports: u64, fund_bump: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let limit = &mut ctx.accounts.limit;
+        let recipient = &mut ctx.accounts.recipient;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the signer, as it could be removed after the limit was created.
+        state.signer_index(&signer.key())?;
+
+        // Checks the spending limit in the current period.
+        limit.refresh(Clock::get()?.unix_timestamp);
+        require_gte!(limit.available(), lamports, Error::SpendingLimitExceeded);
+
+        // Checks the multisig fund balance.
+        require_gte!(state.balance, lamports, Error::NotEnoughFundBalance);
+
+        // Fund to the recipient.
+        let from = fund.to_account_info();
+        let to = recipient.to_account_info();
+        State::transfer_fund(state, &from, &to, lamports, fund_bump)?;
+
+        // Update the balance and the spent lamports.
+        state.sub_balance(lamports)?;
+        limit.spent = limit
+            .spent
+            .checked_add(lamports)
+            .ok_or(Error::LamportsOverflow)?;
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(Spent {
+            state: state.key(),
+            signer: signer.key(),
+            recipient: recipient.key(),
+            lamports,
+            spent: limit.spent,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a queued batch of the native SOL payments.
+    ///
+    /// The payments are approved as one unit by [`multisig_lite::approve_batch`]
+    /// and executed by [`multisig_lite::execute_batch`], possibly across
+    /// multiple transactions.  The total lamports of the payments are
+    /// reserved from the multisig fund balance, and the batch account
+    /// creation fee will be given back to the creator from the multisig fund.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to create a batch of the payroll payments on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::{keypair::Keypair, Signer};
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Temporary batch keypair.
+    /// let batch = Keypair::new();
+    ///
+    /// // Pays 1 SOL to each of the 24 recipients.
+    /// let payments = (0..24)
+    ///     .map(|_| multisig_lite::Payment {
+    ///         recipient: Pubkey::new_unique(),
+    ///         lamports: LAMPORTS_PER_SOL,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Creates a pending batch.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CreateBatch {
+    ///         creator: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         batch: batch.pubkey(),
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::CreateBatch { payments, fund_bump })
+    ///     .signer(funder.as_ref())
+    ///     .signer(&batch)
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_batch(
+        ctx: Context<CreateBatch>,
+        payments: Vec<Payment>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let batch = &mut ctx.accounts.batch;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the creator.
+        let creator_key = creator.key();
+        let creator_index = state.signer_index(&creator_key)?;
+        require!(
+            state.has_role(creator_index, Role::PROPOSER),
+            Error::NotProposer
+        );
+
+        // Check the current transfer queue.
+        state.validate_queue()?;
+
+        // Checks the payments.
+        require!(!payments.is_empty(), Error::InvalidPayments);
+        require_gte!(
+            Batch::MAX_PAYMENTS as usize,
+            payments.len(),
+            Error::InvalidPayments
+        );
+
+        // Checks the multisig fund balance, which covers both the
+        // payments and the rent fee given back to the creator.
+        let lamports = Batch::lamports(&payments)?;
+        let rent = batch.to_account_info().lamports();
+        let total = lamports.checked_add(rent).ok_or(Error::LamportsOverflow)?;
+        require_gte!(state.balance, total, Error::NotEnoughFundBalance);
+
+        // Giving back the rent fee to the creator.
+        let from = fund.to_account_info();
+        let to = creator.to_account_info();
+        State::transfer_fund(state, &from, &to, rent, fund_bump)?;
+        state.sub_balance(rent)?;
+
+        // Initializes the batch account, and queue it
+        // under multisig account for the future execution.
+        batch.creator = creator_key;
+        batch.payments = payments;
+        batch.executed = 0;
+        batch.signed = vec![false; state.signers.len()];
+        state.sub_balance(lamports)?;
+        state.add_pending(lamports)?;
+        state.queue.push(batch.key());
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(BatchCreated {
+            state: state.key(),
+            batch: batch.key(),
+            creator: creator_key,
+            payments: batch.payments.len() as u8,
+            lamports,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels a queued batch.
+    ///
+    /// It gives the lamports of the payments not executed yet back to
+    /// the multisig balance and closes the batch account.  The creator
+    /// of the batch can cancel it by itself.  Otherwise, it requires
+    /// the m-of-n quorum, passed as the signer remaining accounts.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to cancel the first pending batch on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let creator = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, creator.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", creator.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Gets the first pending batch.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let batch = state
+    ///     .queue
+    ///     .into_iter()
+    ///     .find(|pubkey| program.account::<multisig_lite::Batch>(*pubkey).is_ok())
+    ///     .ok_or("no pending batch")?;
+    ///
+    /// // Cancels the batch.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CancelBatch {
+    ///         signer: creator.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         batch,
+    ///     })
+    ///     .args(multisig_lite::instruction::CancelBatch { fund_bump })
+    ///     .signer(creator.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_batch(ctx: Context<CancelBatch>, fund_bump: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let batch = &mut ctx.accounts.batch;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the signer.
+        let signer_key = signer.key();
+        state.signer_index(&signer_key)?;
+
+        // Checks the m-of-n quorum unless it's cancelled by the creator.
+        if batch.creator != signer_key {
+            state.validate_quorum(signer, ctx.remaining_accounts)?;
+        }
+
+        // Gives back the reserved lamports of the unexecuted
+        // payments and update the queue.
+        let batch_key = batch.key();
+        let lamports = Batch::lamports(&batch.payments[batch.executed as usize..])?;
+        state.add_balance(lamports)?;
+        state.sub_pending(lamports)?;
+        state.queue.retain(|pubkey| *pubkey != batch_key);
+
+        // The rent fee goes back to the fund by the close constraint.
+        state.add_balance(batch.to_account_info().lamports())?;
+
+        emit!(BatchCancelled {
+            state: state.key(),
+            batch: batch_key,
+            signer: signer_key,
+            lamports,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Approves the queued batch.
+    ///
+    /// It only records the approval of the signer.  The batch is
+    /// executed by [`multisig_lite::execute_batch`] once it meets
+    /// the m number of signers approval.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to approve pending batches on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Approves the pending batches one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for batch_pubkey in state.queue {
+    ///     if program.account::<multisig_lite::Batch>(batch_pubkey).is_err() {
+    ///         continue; // Skips the transfers and the proposals.
+    ///     }
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::ApproveBatch {
+    ///             signer: signer.pubkey(),
+    ///             state: state_pda,
+    ///             batch: batch_pubkey,
+    ///         })
+    ///         .args(multisig_lite::instruction::ApproveBatch {})
+    ///         .signer(signer.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn approve_batch(ctx: Context<ApproveBatch>) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let batch = &mut ctx.accounts.batch;
+
+        // Checks the signer.
+        let signer_index = state.signer_index(&signer.key())?;
+        require!(
+            state.has_role(signer_index, Role::APPROVER),
+            Error::NotApprover
+        );
+
+        // Records the approval.
+        batch.signed[signer_index] = true;
+
+        emit!(BatchApproved {
+            state: state.key(),
+            batch: batch.key(),
+            signer: signer.key(),
+            approvals: state.approvals(&batch.signed) as u16,
+        });
+
+        Ok(())
+    }
+
+    /// Executes the approved batch.
+    ///
+    /// It pays the next payments of the batch to the recipients passed
+    /// as the remaining accounts, in the order of the payments.  The
+    /// batch can be executed across multiple transactions, by passing
+    /// the subset of the recipients each time.  The batch account is
+    /// closed once all the payments are executed.  The signer with the
+    /// [`Role::EXECUTOR`] role can execute the batch once it meets the
+    /// m number of signers approval.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to execute the approved batches, ten payments per
+    /// transaction, on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let executor = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, executor.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", executor.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Executes the pending batches one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for batch_pubkey in state.queue {
+    ///     let batch: multisig_lite::Batch = match program.account(batch_pubkey) {
+    ///         Ok(batch) => batch,
+    ///         Err(_) => continue, // Skips the transfers and the proposals.
+    ///     };
+    ///
+    ///     // Executes the remaining payments in chunks.
+    ///     let payments = &batch.payments[batch.executed as usize..];
+    ///     for chunk in payments.chunks(10) {
+    ///         let remaining_accounts: Vec<_> = chunk
+    ///             .iter()
+    ///             .map(|payment| AccountMeta::new(payment.recipient, false))
+    ///             .collect();
+    ///
+    ///         let sig = program
+    ///             .request()
+    ///             .accounts(multisig_lite::accounts::ExecuteBatch {
+    ///                 executor: executor.pubkey(),
+    ///                 state: state_pda,
+    ///                 fund: fund_pda,
+    ///                 batch: batch_pubkey,
+    ///             })
+    ///             .args(multisig_lite::instruction::ExecuteBatch { fund_bump })
+    ///             .accounts(remaining_accounts)
+    ///             .signer(executor.as_ref())
+    ///             .send()?;
+    ///
+    ///         println!("{sig}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn execute_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteBatch<'info>>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let executor = &ctx.accounts.executor;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let batch = &mut ctx.accounts.batch;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the executor.
+        let executor_index = state.signer_index(&executor.key())?;
+        require!(
+            state.has_role(executor_index, Role::EXECUTOR),
+            Error::NotExecutor
+        );
+
+        // Checks if the batch is approved.
+        require!(state.is_approved(&batch.signed), Error::ThresholdNotMet);
+
+        // At least one recipient is required to make a progress.
+        require!(
+            !ctx.remaining_accounts.is_empty(),
+            Error::MissingRecipientAccountInfo
+        );
+
+        // Fund to the recipients of the next payments.
+        let from = fund.to_account_info();
+        let start = batch.executed as usize;
+        let payments = &batch.payments[start..];
+        let mut lamports: u64 = 0;
+        for (payment, recipient) in payments.iter().zip(ctx.remaining_accounts) {
+            require_keys_eq!(payment.recipient, recipient.key(), Error::InvalidRecipient);
+            State::transfer_fund(state, &from, recipient, payment.lamports, fund_bump)?;
+            lamports = lamports
+                .checked_add(payment.lamports)
+                .ok_or(Error::LamportsOverflow)?;
+        }
+        let executed = start + ctx.remaining_accounts.len().min(payments.len());
+        batch.executed = executed as u8;
+
+        // Update the pending lamports.
+        state.sub_pending(lamports)?;
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        // Closes the batch account once all the payments are executed.
+        let batch_key = batch.key();
+        if batch.is_done() {
+            let from = batch.to_account_info();
+            let to = fund.to_account_info();
+            let rent = State::close_account(state, &from, &to, fund_bump)?;
+            state.add_balance(rent)?;
+            state.queue.retain(|pubkey| *pubkey != batch_key);
+        }
+
+        emit!(BatchExecuted {
+            state: state.key(),
+            batch: batch_key,
+            lamports,
+            executed: batch.executed,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a queued proposal of the arbitrary instruction.
+    ///
+    /// The instruction will be executed by [`multisig_lite::execute_proposal`]
+    /// with the multisig fund PDA signature, once it meets the m number of
+    /// signers approval.  Proposal account creation fee will be given back
+    /// to the creator of the proposal from the multisig fund.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to propose a memo instruction signed by the multisig fund
+    /// on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    /// use std::str::FromStr;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::{keypair::Keypair, Signer};
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let funder = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Temporary proposal keypair.
+    /// let proposal = Keypair::new();
+    ///
+    /// // The memo instruction signed by the multisig fund PDA.
+    /// let memo = Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")?;
+    /// let keys = vec![multisig_lite::ProposalAccount {
+    ///     pubkey: fund_pda,
+    ///     is_signer: true,
+    ///     is_writable: false,
+    /// }];
+    ///
+    /// // Creates a pending proposal.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CreateProposal {
+    ///         creator: funder.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         proposal: proposal.pubkey(),
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::CreateProposal {
+    ///         program: memo,
+    ///         keys,
+    ///         data: b"Hello from the multisig".to_vec(),
+    ///         fund_bump,
+    ///     })
+    ///     .signer(funder.as_ref())
+    ///     .signer(&proposal)
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        program: Pubkey,
+        keys: Vec<ProposalAccount>,
+        data: Vec<u8>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the creator.
+        let creator_key = creator.key();
+        let creator_index = state.signer_index(&creator_key)?;
+        require!(
+            state.has_role(creator_index, Role::PROPOSER),
+            Error::NotProposer
+        );
+
+        // Check the current transaction queue.
+        state.validate_queue()?;
+
+        // Checks the multisig fund balance for the rent fee.
+        let rent = proposal.to_account_info().lamports();
+        require_gte!(state.balance, rent, Error::NotEnoughFundBalance);
+
+        // Giving back the rent fee to the creator.
+        let from = fund.to_account_info();
+        let to = creator.to_account_info();
+        State::transfer_fund(state, &from, &to, rent, fund_bump)?;
+        state.sub_balance(rent)?;
+
+        // Initializes the proposal account, and
+        // queue it under multisig account for the
+        // future execution.
+        proposal.creator = creator_key;
+        proposal.program = program;
+        proposal.keys = keys;
+        proposal.data = data;
+        proposal.signed = vec![false; state.signers.len()];
+        state.queue.push(proposal.key());
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(ProposalCreated {
+            state: state.key(),
+            proposal: proposal.key(),
+            creator: creator_key,
+            program,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels a queued proposal.
+    ///
+    /// It closes the proposal account without executing the proposal
+    /// instruction.  The creator of the proposal can cancel it by
+    /// itself.  Otherwise, it requires the m-of-n quorum, passed as
+    /// the signer remaining accounts.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to cancel the first pending proposal on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let creator = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, creator.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", creator.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Gets the first pending proposal.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// let proposal = state
+    ///     .queue
+    ///     .into_iter()
+    ///     .find(|pubkey| program.account::<multisig_lite::Proposal>(*pubkey).is_ok())
+    ///     .ok_or("no pending proposal")?;
+    ///
+    /// // Cancels the proposal.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::CancelProposal {
+    ///         signer: creator.pubkey(),
+    ///         state: state_pda,
+    ///         fund: fund_pda,
+    ///         proposal,
+    ///     })
+    ///     .args(multisig_lite::instruction::CancelProposal { fund_bump })
+    ///     .signer(creator.as_ref())
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_proposal(ctx: Context<CancelProposal>, fund_bump: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the signer.
+        let signer_key = signer.key();
+        state.signer_index(&signer_key)?;
+
+        // Checks the m-of-n quorum unless it's cancelled by the creator.
+        if proposal.creator != signer_key {
+            state.validate_quorum(signer, ctx.remaining_accounts)?;
+        }
+
+        // Update the queue.
+        let proposal_key = proposal.key();
+        state.queue.retain(|pubkey| *pubkey != proposal_key);
+
+        // The rent fee goes back to the fund by the close constraint.
+        state.add_balance(proposal.to_account_info().lamports())?;
+
+        emit!(ProposalCancelled {
+            state: state.key(),
+            proposal: proposal_key,
+            signer: signer_key,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Approves the queued proposal.
+    ///
+    /// It only records the approval of the signer.  The proposal
+    /// is executed by [`multisig_lite::execute_proposal`] once it
+    /// meets the m number of signers approval.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to approve pending proposals on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Approves the pending proposals one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for proposal_pubkey in state.queue {
+    ///     if program.account::<multisig_lite::Proposal>(proposal_pubkey).is_err() {
+    ///         continue; // Skips the transfers.
+    ///     }
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::ApproveProposal {
+    ///             signer: signer.pubkey(),
+    ///             state: state_pda,
+    ///             proposal: proposal_pubkey,
+    ///         })
+    ///         .args(multisig_lite::instruction::ApproveProposal {})
+    ///         .signer(signer.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &ctx.accounts.state;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // Checks the signer.
+        let signer_index = state.signer_index(&signer.key())?;
+        require!(
+            state.has_role(signer_index, Role::APPROVER),
+            Error::NotApprover
+        );
+
+        // Records the approval.
+        proposal.signed[signer_index] = true;
+
+        emit!(ProposalApproved {
+            state: state.key(),
+            proposal: proposal.key(),
+            signer: signer.key(),
+            approvals: state.approvals(&proposal.signed) as u16,
+        });
+
+        Ok(())
+    }
+
+    /// Executes the approved proposal.
+    ///
+    /// It executes the proposal instruction with the multisig fund PDA
+    /// signature and closes the proposal account.  The signer with the
+    /// [`Role::EXECUTOR`] role can execute the proposal once it meets
+    /// the m number of signers approval.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to execute the approved proposals on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let executor = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, executor.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", executor.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    ///
+    /// // Executes the pending proposals one by one.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// for proposal_pubkey in state.queue {
+    ///     let proposal: multisig_lite::Proposal = match program.account(proposal_pubkey) {
+    ///         Ok(proposal) => proposal,
+    ///         Err(_) => continue, // Skips the transfers.
+    ///     };
+    ///
+    ///     // The proposal instruction accounts, except the multisig fund PDA,
+    ///     // and the program to execute.
+    ///     let mut remaining_accounts: Vec<_> = proposal
+    ///         .keys
+    ///         .iter()
+    ///         .filter(|key| key.pubkey != fund_pda)
+    ///         .map(|key| AccountMeta {
+    ///             pubkey: key.pubkey,
+    ///             is_signer: key.is_signer,
+    ///             is_writable: key.is_writable,
+    ///         })
+    ///         .collect();
+    ///     remaining_accounts.push(AccountMeta::new_readonly(proposal.program, false));
+    ///
+    ///     let sig = program
+    ///         .request()
+    ///         .accounts(multisig_lite::accounts::ExecuteProposal {
+    ///             executor: executor.pubkey(),
+    ///             state: state_pda,
+    ///             fund: fund_pda,
+    ///             proposal: proposal_pubkey,
+    ///         })
+    ///         .args(multisig_lite::instruction::ExecuteProposal { fund_bump })
+    ///         .accounts(remaining_accounts)
+    ///         .signer(executor.as_ref())
+    ///         .send()?;
+    ///
+    ///     println!("{sig}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+        fund_bump: u8,
+    ) -> Result<()> {
+        let executor = &ctx.accounts.executor;
+        let state = &mut ctx.accounts.state;
+        let fund = &mut ctx.accounts.fund;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // Validate the multisig fund account.
+        State::validate_fund(state, fund, fund_bump)?;
+
+        // Checks the executor.
+        let executor_index = state.signer_index(&executor.key())?;
+        require!(
+            state.has_role(executor_index, Role::EXECUTOR),
+            Error::NotExecutor
+        );
+
+        // Checks the threshold.
+        require!(state.is_approved(&proposal.signed), Error::ThresholdNotMet);
+
+        // CPI to execute the instruction, signed by the fund PDA.
+        let ix = proposal.instruction();
+        let mut accounts = vec![fund.to_account_info()];
+        accounts.extend_from_slice(ctx.remaining_accounts);
+        let state_key = state.key();
+        let seed = [b"fund", state_key.as_ref(), &[fund_bump]];
+        invoke_signed(&ix, &accounts, &[&seed])?;
+
+        // Closes the proposal account.
+        let from = proposal.to_account_info();
+        let to = fund.to_account_info();
+        let rent = State::close_account(state, &from, &to, fund_bump)?;
+        state.add_balance(rent)?;
+
+        // Update the queue.
+        let proposal_key = proposal.key();
+        state.queue.retain(|pubkey| *pubkey != proposal_key);
+
+        // Checks the fund still covers the queued transfers.
+        state.validate_reserve(fund)?;
+
+        emit!(ProposalExecuted {
+            state: state_key,
+            proposal: proposal_key,
+            program: proposal.program,
+            queue_len: state.queue.len() as u8,
+        });
+
+        Ok(())
+    }
+
+    /// Adds a new signer to the multisig account.
+    ///
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts, and is not allowed while there are pending transfers.
+    /// The new signer votes with the weight of one.
+    ///
+    /// # Examples
+    ///
+    /// Here is how to add a new signer on Devnet:
+    ///
+    /// ```no_run
+    /// use std::rc::Rc;
+    ///
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
+    /// use solana_sdk::pubkey::Pubkey;
+    /// use solana_sdk::signature::read_keypair_file;
+    /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
+    ///
+    /// use anchor_client::{Client, Cluster};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let url = Cluster::Devnet;
+    /// let signer = Rc::new(read_keypair_file(
+    ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
+    /// )?);
+    /// let opts = CommitmentConfig::processed();
+    /// let pid = multisig_lite::id();
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
+    ///
+    /// // Gets the PDAs.
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
+    ///
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Adds a new signer.
+    /// let sig = program
+    ///     .request()
+    ///     .accounts(multisig_lite::accounts::AddSigner {
+    ///         signer: signer.pubkey(),
+    ///         state: state_pda,
+    ///         system_program: system_program::id(),
+    ///     })
+    ///     .args(multisig_lite::instruction::AddSigner {
+    ///         pubkey: Pubkey::new_unique(),
+    ///     })
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
+    ///     .send()?;
+    ///
+    /// println!("{sig}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn add_signer(ctx: Context<AddSigner>, pubkey: Pubkey) -> Result<()> {
+        let signer = &ctx.accounts.signer;
+        let state = &mut ctx.accounts.state;
 
-    /// Creates a multisig account.
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
+
+        // Keeps the transfer approvals consistent.
+        require!(!state.is_locked(), Error::AccountLocked);
+
+        // Checks the uniqueness of signer's address.
+        require!(!state.signers.contains(&pubkey), Error::DuplicateSigner);
+        require_gt!(
+            State::MAX_SIGNERS as usize,
+            state.signers.len(),
+            Error::TooManySigners
+        );
+
+        state.signers.push(pubkey);
+        state.roles.push(Role::ALL);
+        state.weights.push(1);
+
+        emit!(SignerAdded {
+            state: state.key(),
+            signer: signer.key(),
+            pubkey,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the signer from the multisig account.
     ///
-    /// It's restricted one multisig account to each funder Pubkey,
-    /// as it's used for the multisig PDA address generation.
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts, and is not allowed while there are pending transfers.
+    /// The threshold should be lowered in advance in case it's
+    /// higher than the summed weight of the remaining signers.
     ///
     /// # Examples
     ///
-    /// Here is how you create a multisig account on Devnet:
+    /// Here is how to remove the signer on Devnet:
     ///
     /// ```no_run
     /// use std::rc::Rc;
     ///
     /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
     /// use solana_sdk::pubkey::Pubkey;
     /// use solana_sdk::signature::read_keypair_file;
     /// use solana_sdk::signer::Signer;
@@ -615,35 +5413,49 @@ pub mod multisig_lite {
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let url = Cluster::Devnet;
-    /// let funder = Rc::new(read_keypair_file(
+    /// let signer = Rc::new(read_keypair_file(
     ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
     /// )?);
     /// let opts = CommitmentConfig::processed();
     /// let pid = multisig_lite::id();
-    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
     ///
     /// // Gets the PDAs.
-    /// let (state_pda, state_bump) =
-    ///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
-    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
     ///
-    /// // Creates a multisig account.
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Removes the signer.
     /// let sig = program
     ///     .request()
-    ///     .accounts(multisig_lite::accounts::Create {
-    ///         funder: funder.pubkey(),
+    ///     .accounts(multisig_lite::accounts::RemoveSigner {
+    ///         signer: signer.pubkey(),
     ///         state: state_pda,
-    ///         fund: fund_pda,
     ///         system_program: system_program::id(),
     ///     })
-    ///     .args(multisig_lite::instruction::Create {
-    ///         m: 2, // m as in m/n.
-    ///         signers: vec![funder.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()],
-    ///         q: 10, // transfer queue limit.
-    ///         _state_bump: state_bump,
-    ///         fund_bump,
+    ///     .args(multisig_lite::instruction::RemoveSigner {
+    ///         pubkey: Pubkey::new_unique(),
     ///     })
-    ///     .signer(funder.as_ref())
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
     ///     .send()?;
     ///
     /// println!("{sig}");
@@ -651,99 +5463,101 @@ pub mod multisig_lite {
     /// # }
     /// ```
     #[allow(clippy::result_large_err)]
-    pub fn create(
-        ctx: Context<Create>,
-        m: u8,
-        signers: Vec<Pubkey>,
-        q: u8,
-        _state_bump: u8,
-        fund_bump: u8,
-    ) -> Result<()> {
-        let funder = &mut ctx.accounts.funder;
+    pub fn remove_signer(ctx: Context<RemoveSigner>, pubkey: Pubkey) -> Result<()> {
+        let signer = &ctx.accounts.signer;
         let state = &mut ctx.accounts.state;
-        let fund = &mut ctx.accounts.fund;
 
-        // At least one signer is required.
-        require_gte!(m, State::MIN_SIGNERS, Error::NoSigners);
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
 
-        // Validate the multisig fund account.
-        State::validate_fund(state, fund, fund_bump)?;
+        // Keeps the transfer approvals consistent.
+        require!(!state.is_locked(), Error::AccountLocked);
 
-        // Checks the uniqueness of signer's address.
-        let signers: HashSet<_> = signers.into_iter().collect();
-        require_gte!(signers.len(), State::MIN_SIGNERS as usize, Error::NoSigners);
+        // Checks the remaining signers.
+        let signer_index = state.signer_index(&pubkey)?;
+        let n = state.signers.len() - 1;
+        require_gte!(n, State::MIN_SIGNERS as usize, Error::NoSigners);
+
+        state.signers.remove(signer_index);
+        state.roles.remove(signer_index);
+        state.weights.remove(signer_index);
         require_gte!(
-            State::MAX_SIGNERS as usize,
-            signers.len(),
-            Error::TooManySigners
+            state.approver_weight(),
+            state.m as usize,
+            Error::ThresholdTooHigh
         );
 
-        let threshold = m as usize;
-        require_gte!(signers.len(), threshold, Error::ThresholdTooHigh);
-
-        // Creates a fund account.
-        State::create_fund_account(state, fund, funder, fund_bump)?;
-
-        // Initializes the multisig state account.
-        state.m = m;
-        state.signers = signers.into_iter().collect();
-        state.signed = vec![false; state.signers.len()];
-        state.fund = fund.key();
-        state.balance = 0;
-        state.q = State::valid_q(q);
+        emit!(SignerRemoved {
+            state: state.key(),
+            signer: signer.key(),
+            pubkey,
+        });
 
         Ok(())
     }
 
-    /// Funds lamports to the multisig fund account.
+    /// Changes the threshold of the multisig account.
     ///
-    /// The funding is only allowed by the multisig account funder.
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts, with the current threshold.
     ///
     /// # Examples
     ///
-    /// Here is how to fund to the multisig account on Devnet:
+    /// Here is how to change the threshold on Devnet:
     ///
     /// ```no_run
     /// use std::rc::Rc;
     ///
     /// use solana_sdk::commitment_config::CommitmentConfig;
+    /// use solana_sdk::instruction::AccountMeta;
     /// use solana_sdk::pubkey::Pubkey;
-    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
     /// use solana_sdk::signature::read_keypair_file;
     /// use solana_sdk::signer::Signer;
-    /// use solana_sdk::system_program;
     ///
     /// use anchor_client::{Client, Cluster};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let url = Cluster::Devnet;
-    /// let funder = Rc::new(read_keypair_file(
+    /// let signer = Rc::new(read_keypair_file(
     ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
     /// )?);
     /// let opts = CommitmentConfig::processed();
     /// let pid = multisig_lite::id();
-    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
     ///
     /// // Gets the PDAs.
-    /// let (state_pda, state_bump) =
-    ///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
-    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
     ///
-    /// // Funds the multisig account.
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
+    ///
+    /// // Changes the threshold to 2.
     /// let sig = program
     ///     .request()
-    ///     .accounts(multisig_lite::accounts::Fund {
-    ///         funder: funder.pubkey(),
+    ///     .accounts(multisig_lite::accounts::ChangeThreshold {
+    ///         signer: signer.pubkey(),
     ///         state: state_pda,
-    ///         fund: fund_pda,
-    ///         system_program: system_program::id(),
-    ///     })
-    ///     .args(multisig_lite::instruction::Fund {
-    ///         lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)
-    ///         _state_bump: state_bump,
-    ///         fund_bump,
     ///     })
-    ///     .signer(funder.as_ref())
+    ///     .args(multisig_lite::instruction::ChangeThreshold { m: 2 })
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
     ///     .send()?;
     ///
     /// println!("{sig}");
@@ -751,84 +5565,97 @@ pub mod multisig_lite {
     /// # }
     /// ```
     #[allow(clippy::result_large_err)]
-    pub fn fund(ctx: Context<Fund>, lamports: u64, _state_bump: u8, fund_bump: u8) -> Result<()> {
-        let funder = &ctx.accounts.funder;
+    pub fn change_threshold(ctx: Context<ChangeThreshold>, m: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
         let state = &mut ctx.accounts.state;
-        let fund = &mut ctx.accounts.fund;
 
-        // Validate the multisig fund account.
-        State::validate_fund(state, fund, fund_bump)?;
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
 
-        // CPI to transfer fund to the multisig fund account.
-        let ix = system_instruction::transfer(&funder.key(), &fund.key(), lamports);
-        let accounts = [funder.to_account_info(), fund.to_account_info()];
-        invoke(&ix, &accounts)?;
+        // Checks the new threshold.
+        require_gte!(m, State::MIN_SIGNERS, Error::NoSigners);
+        require_gte!(state.approver_weight(), m as usize, Error::ThresholdTooHigh);
 
-        // Update the balance.
-        state.balance += lamports;
+        state.m = m;
+
+        emit!(ThresholdChanged {
+            state: state.key(),
+            signer: signer.key(),
+            m,
+        });
 
         Ok(())
     }
 
-    /// Creates a queued transfer lamports to the recipient.
+    /// Sets the roles of the signer.
     ///
-    /// Transfer account creation fee will be given back to the
-    /// creator of the transfer from the multisig fund.
+    /// The [`Role`] flags limit what the signer can do, e.g. an
+    /// accounting bot may propose the transfers but not approve them.
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts, and is not allowed while there are pending transfers.
+    /// The number of the approvers should not go below the threshold.
     ///
     /// # Examples
     ///
-    /// Here is how to create a pending transfer on Devnet:
+    /// Here is how to make a signer a proposer only on Devnet:
     ///
     /// ```no_run
     /// use std::rc::Rc;
     ///
     /// use solana_sdk::commitment_config::CommitmentConfig;
-    /// use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    /// use solana_sdk::instruction::AccountMeta;
     /// use solana_sdk::pubkey::Pubkey;
     /// use solana_sdk::signature::read_keypair_file;
-    /// use solana_sdk::signer::{keypair::Keypair, Signer};
-    /// use solana_sdk::system_program;
+    /// use solana_sdk::signer::Signer;
     ///
     /// use anchor_client::{Client, Cluster};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let url = Cluster::Devnet;
-    /// let funder = Rc::new(read_keypair_file(
+    /// let signer = Rc::new(read_keypair_file(
     ///     shellexpand::tilde("~/.config/solana/id.json").as_ref(),
     /// )?);
     /// let opts = CommitmentConfig::processed();
     /// let pid = multisig_lite::id();
-    /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
+    /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
     ///
     /// // Gets the PDAs.
-    /// let (state_pda, state_bump) =
-    ///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
-    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
     ///
-    /// // Temporary transfer keypair.
-    /// //
-    /// // This is only required for the transaction signature and
-    /// // won't be required once the transaction is recorded on the
-    /// // ledger.
-    /// let transfer = Keypair::new();
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
     ///
-    /// // Creates a pending transfer.
+    /// // Makes the bot a proposer only.
+    /// let bot = Pubkey::new_unique(); // one of the multisig signers.
     /// let sig = program
     ///     .request()
-    ///     .accounts(multisig_lite::accounts::CreateTransfer {
-    ///         creator: funder.pubkey(),
+    ///     .accounts(multisig_lite::accounts::SetRoles {
+    ///         signer: signer.pubkey(),
     ///         state: state_pda,
-    ///         fund: fund_pda,
-    ///         transfer: transfer.pubkey(),
-    ///         system_program: system_program::id(),
     ///     })
-    ///     .args(multisig_lite::instruction::CreateTransfer {
-    ///         recipient: Pubkey::new_unique(),
-    ///         lamports: 1_000_000 * LAMPORTS_PER_SOL, // 1M SOL!? :)
-    ///         fund_bump,
+    ///     .args(multisig_lite::instruction::SetRoles {
+    ///         pubkey: bot,
+    ///         roles: multisig_lite::Role::PROPOSER,
     ///     })
-    ///     .signer(funder.as_ref())
-    ///     .signer(&transfer)
+    ///     .accounts(remaining_accounts)
+    ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
     ///     .send()?;
     ///
     /// println!("{sig}");
@@ -836,58 +5663,48 @@ pub mod multisig_lite {
     /// # }
     /// ```
     #[allow(clippy::result_large_err)]
-    pub fn create_transfer(
-        ctx: Context<CreateTransfer>,
-        recipient: Pubkey,
-        lamports: u64,
-        fund_bump: u8,
-    ) -> Result<()> {
-        let creator = &ctx.accounts.creator;
+    pub fn set_roles(ctx: Context<SetRoles>, pubkey: Pubkey, roles: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
         let state = &mut ctx.accounts.state;
-        let fund = &mut ctx.accounts.fund;
-        let transfer = &mut ctx.accounts.transfer;
-
-        // Checks if the account is locked.
-        require!(!state.is_locked(), Error::AccountLocked);
-
-        // Validate the multisig fund account.
-        State::validate_fund(state, fund, fund_bump)?;
 
-        // Checks the creator.
-        let creator_key = creator.key();
-        let signers = &state.signers;
-        require!(signers.contains(&creator_key), Error::InvalidSigner);
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
 
-        // Check the current transfer queue.
-        state.validate_queue()?;
+        // Keeps the transfer approvals consistent.
+        require!(!state.is_locked(), Error::AccountLocked);
 
-        // Checks the multisig fund balance.
-        require_gte!(state.balance, lamports, Error::NotEnoughFundBalance);
+        // Updates the roles of the signer.
+        let signer_index = state.signer_index(&pubkey)?;
+        let roles = roles & Role::ALL;
+        state.roles[signer_index] = roles;
 
-        // Giving back the rent fee to the creator.
-        let from = fund.to_account_info();
-        let to = creator.to_account_info();
-        let rent = transfer.to_account_info().lamports();
-        State::transfer_fund(state, &from, &to, rent, fund_bump)?;
+        // Keeps enough approvers to meet the threshold.
+        require_gte!(
+            state.approver_weight(),
+            state.m as usize,
+            Error::ThresholdTooHigh
+        );
 
-        // Initializes the transfer account, and
-        // queue it under multisig account for the
-        // future transfer execution.
-        transfer.creator = creator_key;
-        transfer.recipient = recipient;
-        transfer.lamports = lamports;
-        state.balance -= lamports;
-        state.queue.push(transfer.key());
+        emit!(RolesChanged {
+            state: state.key(),
+            signer: signer.key(),
+            pubkey,
+            roles,
+        });
 
         Ok(())
     }
 
-    /// Approves the transactions and executes the transfer
-    /// in case the `m` approvals are met.
+    /// Resizes the transfer queue of the multisig account.
+    ///
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts.  The signer pays the additional rent for the larger
+    /// queue, or gets back the rent for the smaller one.  The queue
+    /// can't be shrunk below the number of the pending transactions.
     ///
     /// # Examples
     ///
-    /// Here is how to approve pending transfers on Devnet:
+    /// Here is how to resize the transfer queue on Devnet:
     ///
     /// ```no_run
     /// use std::rc::Rc;
@@ -897,6 +5714,7 @@ pub mod multisig_lite {
     /// use solana_sdk::pubkey::Pubkey;
     /// use solana_sdk::signature::read_keypair_file;
     /// use solana_sdk::signer::Signer;
+    /// use solana_sdk::system_program;
     ///
     /// use anchor_client::{Client, Cluster};
     ///
@@ -910,42 +5728,39 @@ pub mod multisig_lite {
     /// let program = Client::new_with_options(url, signer.clone(), opts).program(pid);
     ///
     /// // Gets the PDAs.
-    /// let (state_pda, state_bump) =
-    ///     Pubkey::find_program_address(&[b"state", signer.pubkey().as_ref()], &pid);
-    /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, _state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", signer.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
     ///
-    /// // Gets the pending transfers and the recipients account info.
-    /// let mut remaining_accounts = vec![];
-    /// let state: multisig_lite::State = program.account(state_pda)?;
-    /// for transfer_pubkey in state.queue {
-    ///     let transfer: multisig_lite::Transfer = program.account(transfer_pubkey)?;
-    ///
-    ///     // Pushes the transfer account.
-    ///     remaining_accounts.push(AccountMeta {
-    ///         pubkey: transfer_pubkey,
-    ///         is_signer: false,
-    ///         is_writable: true,
-    ///     });
-    ///
-    ///     // Pushes the recipient account.
-    ///     remaining_accounts.push(AccountMeta {
-    ///         pubkey: transfer.recipient,
-    ///         is_signer: false,
-    ///         is_writable: true,
-    ///     });
-    /// }
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
+    ///     })
+    ///     .collect();
     ///
-    /// // Approve the multisig account.
+    /// // Grows the transfer queue to 20.
     /// let sig = program
     ///     .request()
-    ///     .accounts(multisig_lite::accounts::Approve {
+    ///     .accounts(multisig_lite::accounts::ResizeQueue {
     ///         signer: signer.pubkey(),
     ///         state: state_pda,
-    ///         fund: fund_pda,
+    ///         system_program: system_program::id(),
     ///     })
-    ///     .args(multisig_lite::instruction::Approve { fund_bump })
+    ///     .args(multisig_lite::instruction::ResizeQueue { q: 20 })
     ///     .accounts(remaining_accounts)
     ///     .signer(signer.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
     ///     .send()?;
     ///
     /// println!("{sig}");
@@ -953,95 +5768,43 @@ pub mod multisig_lite {
     /// # }
     /// ```
     #[allow(clippy::result_large_err)]
-    pub fn approve(ctx: Context<Approve>, fund_bump: u8) -> Result<()> {
+    pub fn resize_queue(ctx: Context<ResizeQueue>, q: u8) -> Result<()> {
         let signer = &ctx.accounts.signer;
         let state = &mut ctx.accounts.state;
-        let fund = &mut ctx.accounts.fund;
-        let remaining_accounts: HashMap<_, _> = ctx
-            .remaining_accounts
-            .iter()
-            .map(|account| (account.key, account))
-            .collect();
-
-        // Validate the multisig fund account.
-        State::validate_fund(state, fund, fund_bump)?;
-
-        // Nothing to approve.
-        require!(!state.is_queue_empty(), Error::AccountEmpty);
-
-        // Checks the signer.
-        let signer_key = signer.key();
-        let signers = &state.signers;
-        let signer_index = match signers.iter().position(|pubkey| *pubkey == signer_key) {
-            None => return Err(Error::InvalidSigner.into()),
-            Some(signer_index) => signer_index,
-        };
-
-        // Due to the single transaction limitation, we allow the multiple approval
-        // so that we take care of the transfer in batch.
-        if !state.signed[signer_index] {
-            state.signed[signer_index] = true;
-        }
-
-        // Checks the threshold.
-        let signed = state.signed.iter().filter(|&signed| *signed).count() as u8;
-        if signed < state.m {
-            return Ok(());
-        }
-
-        // Finds out the executable transactions.
-        let mut executable = Vec::new();
-        let mut remaining = Vec::new();
-        for transfer_addr in &state.queue {
-            let transfer_info = match remaining_accounts.get(transfer_addr) {
-                Some(transfer) => transfer,
-                None => {
-                    remaining.push(*transfer_addr);
-                    continue;
-                }
-            };
-            let mut ref_data = transfer_info.try_borrow_mut_data()?;
-            let mut transfer_data: &[u8] = ref_data.deref_mut();
-            let tx = Transfer::try_deserialize(&mut transfer_data)?;
-            let to = match remaining_accounts.get(&tx.recipient) {
-                None => return Err(Error::MissingRecipientAccountInfo.into()),
-                Some(recipient) => recipient,
-            };
-            executable.push((transfer_info, to, tx.lamports));
-        }
 
-        // There is no executable account info.  Just returns the success.
-        //
-        // This is a case that the approver approved the multisig but didn't
-        // provide the account info.
-        if executable.is_empty() {
-            return Ok(());
-        }
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
 
-        // Executes the queued transfers.
-        let fund = fund.to_account_info();
-        for (transfer, to, lamports) in executable {
-            // Fund to the recipient and closes the transfer account.
-            State::transfer_fund(state, &fund, to, lamports, fund_bump)?;
-            let lamports = transfer.lamports();
-            State::transfer_fund(state, transfer, &fund, lamports, fund_bump)?;
-        }
+        // Keeps the pending transactions in the queue.
+        let q = State::valid_q(q);
+        require_gte!(q as usize, state.queue.len(), Error::QueueTooShort);
 
-        // Update the queue.
-        state.queue = remaining;
+        state.q = q;
 
-        // Reset the signed status once the queue is empty.
-        if state.is_queue_empty() {
-            state.signed.iter_mut().for_each(|signed| *signed = false);
-        }
+        emit!(QueueResized {
+            state: state.key(),
+            signer: signer.key(),
+            q,
+        });
 
         Ok(())
     }
 
     /// Closes the multisig account.
     ///
-    /// It cleans up all the remaining accounts and return those rents
+    /// It requires the m-of-n quorum, passed as the signer remaining
+    /// accounts together with the queued transfer accounts.
+    /// All the queued accounts should be passed in and writable, and
+    /// any other remaining account is rejected unless it's a co-signer.
+    ///
+    /// It cleans up all the remaining accounts and sends the remaining
+    /// fund to the destination.  The rent of the state account goes
     /// back to the funder, original creator of the multisig account.
+    /// The tokens held by the multisig fund token accounts are not
+    /// swept, so please transfer those out with the token transfers
+    /// before closing the multisig account.  The spending limit
+    /// accounts should be removed by [`multisig_lite::remove_spending_limit`]
+    /// as well.
     ///
     /// # Examples
     ///
@@ -1068,35 +5831,54 @@ pub mod multisig_lite {
     /// let program = Client::new_with_options(url, funder.clone(), opts).program(pid);
     ///
     /// // Gets the PDAs.
-    /// let (state_pda, state_bump) =
-    ///     Pubkey::find_program_address(&[b"state", funder.pubkey().as_ref()], &pid);
+    /// let index = 0u64; // the first multisig account of the funder.
+    /// let (state_pda, state_bump) = Pubkey::find_program_address(
+    ///     &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+    ///     &pid,
+    /// );
     /// let (fund_pda, fund_bump) = Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
     ///
-    /// // Gets the remaining transfers to collects the rents.
-    /// let state: multisig_lite::State = program.account(state_pda)?;
-    /// let remaining_accounts: Vec<_> = state
-    ///     .queue
-    ///     .into_iter()
-    ///     .map(|pubkey| AccountMeta {
-    ///         pubkey,
-    ///         is_signer: false,
-    ///         is_writable: true,
+    /// // Other signers to meet the m-of-n quorum.
+    /// let cosigners = [
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer1.json").as_ref())?,
+    ///     read_keypair_file(shellexpand::tilde("~/.config/solana/signer2.json").as_ref())?,
+    /// ];
+    /// let mut remaining_accounts: Vec<_> = cosigners
+    ///     .iter()
+    ///     .map(|cosigner| AccountMeta {
+    ///         pubkey: cosigner.pubkey(),
+    ///         is_signer: true,
+    ///         is_writable: false,
     ///     })
     ///     .collect();
     ///
+    /// // Gets the remaining transfers to collects the rents.
+    /// let state: multisig_lite::State = program.account(state_pda)?;
+    /// remaining_accounts.extend(state.queue.into_iter().map(|pubkey| AccountMeta {
+    ///     pubkey,
+    ///     is_signer: false,
+    ///     is_writable: true,
+    /// }));
+    ///
     /// // close the multisig account.
     /// let sig = program
     ///     .request()
     ///     .accounts(multisig_lite::accounts::Close {
+    ///         signer: funder.pubkey(),
     ///         funder: funder.pubkey(),
     ///         state: state_pda,
     ///         fund: fund_pda,
+    ///         destination: funder.pubkey(),
     ///     })
     ///     .args(multisig_lite::instruction::Close {
+    ///         _index: index,
     ///         _state_bump: state_bump,
     ///         fund_bump,
     ///     })
+    ///     .accounts(remaining_accounts)
     ///     .signer(funder.as_ref())
+    ///     .signer(&cosigners[0])
+    ///     .signer(&cosigners[1])
     ///     .send()?;
     ///
     /// println!("{sig}");
@@ -1104,10 +5886,11 @@ pub mod multisig_lite {
     /// # }
     /// ```
     #[allow(clippy::result_large_err)]
-    pub fn close(ctx: Context<Close>, _state_bump: u8, fund_bump: u8) -> Result<()> {
-        let funder = &mut ctx.accounts.funder;
+    pub fn close(ctx: Context<Close>, _index: u64, _state_bump: u8, fund_bump: u8) -> Result<()> {
+        let signer = &ctx.accounts.signer;
         let state = &mut ctx.accounts.state;
         let fund = &mut ctx.accounts.fund;
+        let destination = &mut ctx.accounts.destination;
         let remaining_accounts: HashMap<_, _> = ctx
             .remaining_accounts
             .iter()
@@ -1117,25 +5900,47 @@ pub mod multisig_lite {
         // Validate the multisig fund account.
         State::validate_fund(state, fund, fund_bump)?;
 
+        // Checks the m-of-n quorum.
+        state.validate_quorum(signer, ctx.remaining_accounts)?;
+
+        // Checks the remaining accounts, which should be either
+        // the multisig co-signers or the queued accounts.
+        for account in ctx.remaining_accounts {
+            if state.queue.contains(account.key) {
+                State::validate_queued_account(account)?;
+            } else {
+                require!(
+                    account.is_signer && state.signers.contains(account.key),
+                    Error::UnexpectedAccount
+                );
+            }
+        }
+
         // Closes the transfer accounts by transfering the
         // rent fee back to the fund account.
         let to = fund.to_account_info();
         for transfer_addr in &state.queue {
             let from = match remaining_accounts.get(transfer_addr) {
                 Some(transfer) => transfer,
-                None => continue,
+                None => return Err(Error::MissingQueuedAccount.into()),
             };
-            let lamports = from.lamports();
-            State::transfer_fund(state, from, &to, lamports, fund_bump)?;
+            State::close_account(state, from, &to, fund_bump)?;
         }
 
         // Closes the multisig fund account by transfering all the lamports
-        // back to the funder.
+        // to the destination.
         let from = fund.to_account_info();
-        let to = funder.to_account_info();
+        let to = destination.to_account_info();
         let lamports = fund.lamports();
         State::transfer_fund(state, &from, &to, lamports, fund_bump)?;
 
+        emit!(Closed {
+            state: state.key(),
+            signer: signer.key(),
+            destination: destination.key(),
+            lamports,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,157 @@
+//! `multisig_list::multisig_list::add_signer` instruction tests.
+
+mod common;
+
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use common::Tester;
+
+#[tokio::test]
+async fn add_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Then adds a new signer by the 3 out of 5 signers.
+    let pubkey = Pubkey::new_unique();
+    assert!(tester
+        .with_signature()
+        .add_signer(pubkey, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.signers.len(), 6);
+    assert_eq!(state.signers[5], pubkey);
+}
+
+#[tokio::test]
+async fn add_signer_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .add_signer(Pubkey::new_unique(), 0, &[1])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn add_signer_with_existing_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let pubkey = tester.signers[4].pubkey();
+    let err = tester
+        .with_signature()
+        .add_signer(pubkey, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6028)),
+    );
+}
+
+#[tokio::test]
+async fn add_signer_with_pending_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund(LAMPORTS_PER_SOL).await;
+    tester.create_transfer(LAMPORTS_PER_SOL / 2).await;
+
+    let err = tester
+        .with_signature()
+        .add_signer(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6002)),
+    );
+}
+
+#[tokio::test]
+async fn add_signer_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .add_signer(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+impl Tester {
+    async fn add_signer(
+        &mut self,
+        pubkey: Pubkey,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::AddSigner {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::AddSigner { pubkey })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
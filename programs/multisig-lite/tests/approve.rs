@@ -1,8 +1,10 @@
 //! `multisig_list::multisig_list::approve` instruction tests.
 
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
 use solana_sdk::commitment_config::CommitmentLevel;
 use solana_sdk::hash::Hash;
-use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::InstructionError;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::keypair::Keypair;
@@ -10,7 +12,9 @@ use solana_sdk::signer::Signer;
 use solana_sdk::system_program;
 use solana_sdk::transaction::{Transaction, TransactionError};
 
-use anchor_client::anchor_lang::AccountDeserialize;
+use solana_program_test::ProgramTestBanksClientExt;
+
+use anchor_client::anchor_lang::{AccountDeserialize, AccountSerialize};
 
 #[tokio::test]
 async fn approve() {
@@ -22,7 +26,151 @@ async fn approve() {
     tester.create_transfers().await;
 
     // Then approves the transfers.
-    assert!(tester.with_signature().approve().await.is_ok());
+    for i in 0..tester.transfers.len() {
+        assert!(tester.with_signature().approve(0, i).await.is_ok());
+
+        let key = tester.transfers[i].transfer;
+        let transfer = tester.get_transfer_account(key).await.unwrap();
+        assert_eq!(transfer.signed, vec![true, false, false, false, false]);
+    }
+
+    // All the transfers are still queued due to the threshold.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue.len(), tester.transfers.len());
+}
+
+#[tokio::test]
+async fn approve_only_the_given_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the first transfer.
+    assert!(tester.with_signature().approve(1, 0).await.is_ok());
+
+    // Only the first transfer is approved, by the second signer.
+    let key = tester.transfers[0].transfer;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.signed, vec![false, true, false, false, false]);
+    let keys: Vec<_> = tester.transfers[1..].iter().map(|t| t.transfer).collect();
+    for key in keys {
+        let transfer = tester.get_transfer_account(key).await.unwrap();
+        assert!(transfer.signed.iter().all(|signed| !*signed));
+    }
+}
+
+#[tokio::test]
+async fn approve_by_m_signers() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    // The transfer is approved but not executed until the execute instruction.
+    let key = tester.transfers[1].transfer;
+    let recipient = tester.transfers[1].recipient;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.signed, vec![true, true, true, false, false]);
+    assert_eq!(tester.get_balance(recipient).await, 0);
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue.len(), tester.transfers.len());
+    assert!(state.queue.contains(&key));
+}
+
+#[tokio::test]
+async fn approve_expired_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates an expired transfer on the multisig account.
+    let now = tester.get_unix_timestamp().await;
+    tester.transfers[1].expire_at = Some(now - 1);
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    let err = tester.with_signature().approve(0, 1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6019)),
+    );
+}
+
+#[tokio::test]
+async fn approve_forged_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // The forged transfer account owned by the other program.
+    tester.transfers[0].transfer = tester.forged;
+    let err = tester.with_signature().approve(0, 0).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(3007)),
+    );
+
+    // The multisig state account in place of the transfer account.
+    tester.transfers[0].transfer = tester.state_pda;
+    let err = tester.with_signature().approve(0, 0).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(3002)),
+    );
+}
+
+#[tokio::test]
+async fn approve_twice() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer twice by the same signer.
+    assert!(tester.with_signature().approve(1, 1).await.is_ok());
+    tester.recent_blockhash = tester
+        .client
+        .get_new_latest_blockhash(&tester.recent_blockhash)
+        .await
+        .unwrap();
+    assert!(tester.with_signature().approve(1, 1).await.is_ok());
+
+    // The approval is counted only once.
+    let key = tester.transfers[1].transfer;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.signed, vec![false, true, false, false, false]);
+}
+
+#[tokio::test]
+async fn approve_not_queued_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // The transfer account not in the multisig queue.
+    tester.transfers[0].transfer = tester.unqueued;
+    let err = tester.with_signature().approve(0, 0).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6013)),
+    );
 }
 
 #[tokio::test]
@@ -34,7 +182,7 @@ async fn approve_without_signature() {
     tester.fund().await;
     tester.create_transfers().await;
 
-    let err = tester.approve().await.err().unwrap();
+    let err = tester.approve(0, 0).await.err().unwrap();
     assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
 }
 
@@ -45,43 +193,99 @@ struct Tester {
     recent_blockhash: Hash,
     with_signature: bool,
     m: u8,
-    signers: Vec<Pubkey>,
+    signers: Vec<std::rc::Rc<Keypair>>,
     q: u8,
+    index: u64,
     state_pda: Pubkey,
     state_bump: u8,
     fund_pda: Pubkey,
     fund_bump: u8,
+    forged: Pubkey,
+    unqueued: Pubkey,
     lamports: u64,
     transfers: Vec<TestTransfer>,
 }
 
 struct TestTransfer {
-    transfer: Keypair,
+    transfer: Pubkey,
     recipient: Pubkey,
     lamports: u64,
+    execute_after: Option<i64>,
+    expire_at: Option<i64>,
 }
 
 impl Tester {
     async fn new() -> Self {
         let pid = multisig_lite::id();
-        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+        let mut program_test = solana_program_test::ProgramTest::new(
             "multisig_lite",
             pid,
             solana_program_test::processor!(multisig_lite::entry),
-        )
-        .start()
-        .await;
+        );
+
+        // Forged transfer account, owned by the other program.
+        let forged = Pubkey::new_unique();
+        let mut data = vec![];
+        multisig_lite::Transfer {
+            creator: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            lamports: LAMPORTS_PER_SOL,
+            mint: None,
+            execute_after: None,
+            expire_at: None,
+            memo: None,
+            signed: vec![true; 5],
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        program_test.add_account(
+            forged,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        // Transfer account owned by the program, but not in the queue.
+        let unqueued = Pubkey::new_unique();
+        let mut data = vec![];
+        multisig_lite::Transfer {
+            creator: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            lamports: LAMPORTS_PER_SOL,
+            mint: None,
+            execute_after: None,
+            expire_at: None,
+            memo: None,
+            signed: vec![false; 5],
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        program_test.add_account(
+            unqueued,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: pid,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
 
         // Wrap the founder keypair to be able to be passed to the anchor program.
         let funder = std::rc::Rc::new(funder);
 
         // Default 5 signers, including the funder.
         let signers = vec![
-            funder.pubkey(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
         ];
 
         // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
@@ -89,18 +293,27 @@ impl Tester {
         let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
 
         // Find PDAs.
-        let (state_pda, state_bump) =
-            Pubkey::find_program_address(&[b"state", (funder.pubkey().as_ref())], &pid);
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
         let (fund_pda, fund_bump) =
             Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
         // Transfers.
         let mut transfers = vec![];
         (0..10).for_each(|i| {
+            let (transfer, _) = Pubkey::find_program_address(
+                &[b"transfer", state_pda.as_ref(), &(i as u64).to_le_bytes()],
+                &pid,
+            );
             transfers.push(TestTransfer {
-                transfer: Keypair::new(),
+                transfer,
                 recipient: Pubkey::new_unique(),
                 lamports: i as u64 * LAMPORTS_PER_SOL,
+                execute_after: None,
+                expire_at: None,
             })
         });
 
@@ -113,10 +326,13 @@ impl Tester {
             m: 3,
             signers,
             q: 10,
+            index,
             state_pda,
             state_bump,
             fund_pda,
             fund_bump,
+            forged,
+            unqueued,
             lamports: 100_000 * LAMPORTS_PER_SOL, // 100k SOL!? :)
             transfers,
         }
@@ -139,7 +355,7 @@ impl Tester {
             .unwrap()
     }
 
-    async fn get_transfer_account(&mut self, key: Pubkey) -> multisig_lite::Transfer {
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
         self.client
             .get_account_with_commitment(key, CommitmentLevel::Processed)
             .await
@@ -148,7 +364,18 @@ impl Tester {
                 let mut data: &[u8] = &account.data;
                 multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
             })
+    }
+
+    async fn get_unix_timestamp(&mut self) -> i64 {
+        self.client
+            .get_sysvar::<Clock>()
+            .await
             .unwrap()
+            .unix_timestamp
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
     }
 
     async fn create(&mut self) {
@@ -163,8 +390,10 @@ impl Tester {
             })
             .args(multisig_lite::instruction::Create {
                 m: self.m,
-                signers: self.signers.clone(),
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
                 q: self.q,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -188,6 +417,7 @@ impl Tester {
             })
             .args(multisig_lite::instruction::Fund {
                 lamports: self.lamports,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -200,10 +430,8 @@ impl Tester {
     }
 
     async fn create_transfers(&mut self) {
-        let mut signers = vec![self.funder.as_ref()];
         let mut ixs = vec![];
         self.transfers.iter().for_each(|transfer| {
-            signers.push(&transfer.transfer);
             ixs.extend(
                 self.program
                     .request()
@@ -211,12 +439,15 @@ impl Tester {
                         creator: self.funder.pubkey(),
                         state: self.state_pda,
                         fund: self.fund_pda,
-                        transfer: transfer.transfer.pubkey(),
+                        transfer: transfer.transfer,
                         system_program: system_program::id(),
                     })
                     .args(multisig_lite::instruction::CreateTransfer {
                         recipient: transfer.recipient,
                         lamports: transfer.lamports,
+                        execute_after: transfer.execute_after,
+                        expire_at: transfer.expire_at,
+                        memo: None,
                         fund_bump: self.fund_bump,
                     })
                     .instructions()
@@ -225,50 +456,37 @@ impl Tester {
         });
 
         let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
-        tx.sign(&signers, self.recent_blockhash);
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
         self.client.process_transaction(tx).await.unwrap();
     }
 
-    async fn approve(&mut self) -> Result<(), solana_program_test::BanksClientError> {
-        // Gets the pending transfers and the recipients account info.
-        let mut remaining_accounts = vec![];
-        let state = self.get_state_account().await;
-        for transfer_pubkey in state.queue {
-            let transfer = self.get_transfer_account(transfer_pubkey).await;
-
-            // Pushes the transfer account.
-            remaining_accounts.push(AccountMeta {
-                pubkey: transfer_pubkey,
-                is_signer: false,
-                is_writable: true,
-            });
-
-            // Pushes the recipient account.
-            remaining_accounts.push(AccountMeta {
-                pubkey: transfer.recipient,
-                is_signer: false,
-                is_writable: true,
-            });
-        }
+    async fn approve(
+        &mut self,
+        signer_index: usize,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let transfer = &self.transfers[transfer_index];
 
         let ixs = self
             .program
             .request()
             .accounts(multisig_lite::accounts::Approve {
-                signer: self.funder.pubkey(),
+                signer: signer.pubkey(),
                 state: self.state_pda,
-                fund: self.fund_pda,
-            })
-            .args(multisig_lite::instruction::Approve {
-                fund_bump: self.fund_bump,
+                transfer: transfer.transfer,
             })
-            .accounts(remaining_accounts)
+            .args(multisig_lite::instruction::Approve {})
             .instructions()
             .unwrap();
 
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
         let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
         if self.with_signature {
-            tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+            tx.sign(&signers, self.recent_blockhash);
         }
         self.client.process_transaction(tx).await
     }
@@ -0,0 +1,289 @@
+//! `multisig_list::multisig_list::approve_batch` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn approve_batch() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a batch on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_batch().await;
+
+    // Approves the batch by the funder.
+    assert!(tester.with_signature().approve_batch(0).await.is_ok());
+
+    let batch = tester.get_batch_account().await.unwrap();
+    assert!(batch.signed[0]);
+    assert_eq!(batch.signed.iter().filter(|signed| **signed).count(), 1);
+    assert_eq!(batch.executed, 0);
+}
+
+#[tokio::test]
+async fn approve_batch_by_m_signers() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a batch on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_batch().await;
+
+    // Approves the batch by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve_batch(signer).await.is_ok());
+    }
+
+    let batch = tester.get_batch_account().await.unwrap();
+    let approvals = batch.signed.iter().filter(|signed| **signed).count();
+    assert_eq!(approvals, tester.m as usize);
+}
+
+#[tokio::test]
+async fn approve_batch_by_non_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a batch on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_batch().await;
+
+    // Replaces the last signer with a non-signer.
+    tester.signers[4] = std::rc::Rc::new(Keypair::new());
+    let err = tester
+        .with_signature()
+        .approve_batch(4)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6011)),
+    );
+}
+
+#[tokio::test]
+async fn approve_batch_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a batch on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_batch().await;
+
+    let err = tester.approve_batch(0).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+    batch: Keypair,
+    payments: Vec<multisig_lite::Payment>,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        // Payments.
+        let payments = (1..=5)
+            .map(|i| multisig_lite::Payment {
+                recipient: Pubkey::new_unique(),
+                lamports: i * LAMPORTS_PER_SOL / 10,
+            })
+            .collect();
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 100 * LAMPORTS_PER_SOL,
+            batch: Keypair::new(),
+            payments,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_batch_account(&mut self) -> Option<multisig_lite::Batch> {
+        self.client
+            .get_account_with_commitment(self.batch.pubkey(), CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Batch::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_batch(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateBatch {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                batch: self.batch.pubkey(),
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateBatch {
+                payments: self.payments.clone(),
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref(), &self.batch], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn approve_batch(
+        &mut self,
+        signer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ApproveBatch {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                batch: self.batch.pubkey(),
+            })
+            .args(multisig_lite::instruction::ApproveBatch {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
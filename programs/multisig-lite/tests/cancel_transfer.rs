@@ -0,0 +1,341 @@
+//! `multisig_list::multisig_list::cancel_transfer` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn cancel_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    let transfer = tester.transfer_pda(0);
+    let lamports = 2_000 * LAMPORTS_PER_SOL;
+    tester.create_transfer(transfer, lamports).await;
+    let rent = tester.get_balance(transfer).await;
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, tester.lamports - lamports - rent);
+    assert_eq!(state.queue, vec![transfer]);
+
+    // Then cancels it by the creator.
+    assert!(tester
+        .with_signature()
+        .cancel_transfer(transfer, 0, &[])
+        .await
+        .is_ok());
+
+    // The lamports are back to the balance.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, tester.lamports);
+    assert!(state.queue.is_empty());
+    assert!(tester.client.get_account(transfer).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn cancel_transfer_by_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    let transfer = tester.transfer_pda(0);
+    tester
+        .create_transfer(transfer, 2_000 * LAMPORTS_PER_SOL)
+        .await;
+
+    // Then cancels it by the 3 out of 5 signers, without the creator.
+    assert!(tester
+        .with_signature()
+        .cancel_transfer(transfer, 1, &[2, 3])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, tester.lamports);
+    assert!(state.queue.is_empty());
+}
+
+#[tokio::test]
+async fn cancel_transfer_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    let transfer = tester.transfer_pda(0);
+    tester
+        .create_transfer(transfer, 2_000 * LAMPORTS_PER_SOL)
+        .await;
+
+    // Only 2 out of 5 signers, without the creator.
+    let err = tester
+        .with_signature()
+        .cancel_transfer(transfer, 1, &[2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn cancel_transfer_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    let transfer = tester.transfer_pda(0);
+    tester
+        .create_transfer(transfer, 2_000 * LAMPORTS_PER_SOL)
+        .await;
+
+    let err = tester
+        .cancel_transfer(transfer, 0, &[])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 100_000 * LAMPORTS_PER_SOL, // 100k SOL!? :)
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    fn transfer_pda(&self, index: u64) -> Pubkey {
+        let (transfer_pda, _) = Pubkey::find_program_address(
+            &[b"transfer", self.state_pda.as_ref(), &index.to_le_bytes()],
+            &self.program.id(),
+        );
+        transfer_pda
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_transfer(&mut self, transfer: Pubkey, lamports: u64) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTransfer {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTransfer {
+                recipient: Pubkey::new_unique(),
+                lamports,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn cancel_transfer(
+        &mut self,
+        transfer: Pubkey,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CancelTransfer {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer,
+            })
+            .args(multisig_lite::instruction::CancelTransfer {
+                fund_bump: self.fund_bump,
+            })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,259 @@
+//! `multisig_list::multisig_list::change_threshold` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn change_threshold() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Then lowers the threshold by the 3 out of 5 signers.
+    assert!(tester
+        .with_signature()
+        .change_threshold(2, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.m, 2);
+}
+
+#[tokio::test]
+async fn change_threshold_to_zero() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .change_threshold(0, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6008)),
+    );
+}
+
+#[tokio::test]
+async fn change_threshold_too_high() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .change_threshold(6, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6010)),
+    );
+}
+
+#[tokio::test]
+async fn change_threshold_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .change_threshold(1, 0, &[1])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn change_threshold_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester.change_threshold(2, 0, &[1, 2]).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn change_threshold(
+        &mut self,
+        m: u8,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ChangeThreshold {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+            })
+            .args(multisig_lite::instruction::ChangeThreshold { m })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
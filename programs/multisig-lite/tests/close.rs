@@ -3,14 +3,15 @@
 use solana_sdk::account::Account;
 use solana_sdk::commitment_config::CommitmentLevel;
 use solana_sdk::hash::Hash;
-use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::keypair::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_program;
 use solana_sdk::transaction::{Transaction, TransactionError};
 
-use anchor_client::anchor_lang::AccountDeserialize;
+use anchor_client::anchor_lang::{AccountDeserialize, AccountSerialize};
 
 #[tokio::test]
 async fn close() {
@@ -21,14 +22,168 @@ async fn close() {
 
     // Make sure state and fund accounts exist.
     assert!(tester.get_state_account().await.is_some());
-    assert!(tester.get_fund_account().await.is_some());
+    let fund = tester.get_fund_account().await.unwrap();
 
-    // Then close it.
-    assert!(tester.with_signature().close().await.is_ok());
+    // Then close it by the 3 out of 5 signers.
+    let destination = Pubkey::new_unique();
+    assert!(tester
+        .with_signature()
+        .close(destination, 0, &[1, 2])
+        .await
+        .is_ok());
 
     // And double check if it's gone.
     assert!(tester.get_state_account().await.is_none());
     assert!(tester.get_fund_account().await.is_none());
+
+    // The remaining fund goes to the destination.
+    let balance = tester.client.get_balance(destination).await.unwrap();
+    assert_eq!(balance, fund.lamports);
+}
+
+#[tokio::test]
+async fn close_by_funder_only() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // The funder can't close the account by itself.
+    let err = tester
+        .with_signature()
+        .close(Pubkey::new_unique(), 0, &[])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+
+    // Make sure state and fund accounts still exist.
+    assert!(tester.get_state_account().await.is_some());
+    assert!(tester.get_fund_account().await.is_some());
+}
+
+#[tokio::test]
+async fn close_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Only 2 out of 5 signers.
+    let err = tester
+        .with_signature()
+        .close(Pubkey::new_unique(), 1, &[2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn close_with_forged_account() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // The forged account is neither a co-signer nor a queued account.
+    let forged = AccountMeta::new(tester.forged, false);
+    let err = tester
+        .with_signature()
+        .with_account(forged)
+        .close(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6033)),
+    );
+
+    // Make sure state and fund accounts still exist.
+    assert!(tester.get_state_account().await.is_some());
+    assert!(tester.get_fund_account().await.is_some());
+}
+
+#[tokio::test]
+async fn close_with_non_signer_cosigner() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // The outsider signs the transaction but is not a multisig signer.
+    let err = tester
+        .with_signature()
+        .with_outsider()
+        .close(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6033)),
+    );
+
+    // Make sure state and fund accounts still exist.
+    assert!(tester.get_state_account().await.is_some());
+    assert!(tester.get_fund_account().await.is_some());
+}
+
+#[tokio::test]
+async fn close_with_readonly_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund(10 * LAMPORTS_PER_SOL).await;
+    tester.create_transfer(LAMPORTS_PER_SOL).await;
+
+    // The queued transfer account should be writable.
+    let err = tester
+        .with_signature()
+        .with_readonly_queue()
+        .close(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6034)),
+    );
+}
+
+#[tokio::test]
+async fn close_without_queued_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer on the multisig account.
+    tester.create().await;
+    tester.fund(10 * LAMPORTS_PER_SOL).await;
+    tester.create_transfer(LAMPORTS_PER_SOL).await;
+
+    // All the queued accounts should be passed in.
+    let err = tester
+        .with_signature()
+        .without_queue()
+        .close(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6038)),
+    );
+
+    // Make sure state and fund accounts still exist.
+    assert!(tester.get_state_account().await.is_some());
+    assert!(tester.get_fund_account().await.is_some());
 }
 
 #[tokio::test]
@@ -38,7 +193,11 @@ async fn close_without_signature() {
     // Creates a multisig account.
     tester.create().await;
 
-    let err = tester.close().await.err().unwrap();
+    let err = tester
+        .close(Pubkey::new_unique(), 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
     assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
 }
 
@@ -48,36 +207,67 @@ struct Tester {
     funder: std::rc::Rc<Keypair>,
     recent_blockhash: Hash,
     with_signature: bool,
+    readonly_queue: bool,
+    without_queue: bool,
     m: u8,
-    signers: Vec<Pubkey>,
+    signers: Vec<std::rc::Rc<Keypair>>,
     q: u8,
+    index: u64,
     state_pda: Pubkey,
     state_bump: u8,
     fund_pda: Pubkey,
     fund_bump: u8,
+    forged: Pubkey,
+    accounts: Vec<AccountMeta>,
+    outsiders: Vec<std::rc::Rc<Keypair>>,
 }
 
 impl Tester {
     async fn new() -> Self {
         let pid = multisig_lite::id();
-        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+        let mut program_test = solana_program_test::ProgramTest::new(
             "multisig_lite",
             pid,
             solana_program_test::processor!(multisig_lite::entry),
-        )
-        .start()
-        .await;
+        );
+
+        // Forged transfer account, owned by the other program.
+        let forged = Pubkey::new_unique();
+        let mut data = vec![];
+        multisig_lite::Transfer {
+            creator: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            lamports: LAMPORTS_PER_SOL,
+            mint: None,
+            execute_after: None,
+            expire_at: None,
+            memo: None,
+            signed: vec![true; 5],
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        program_test.add_account(
+            forged,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
 
         // Wrap the founder keypair to be able to be passed to the anchor program.
         let funder = std::rc::Rc::new(funder);
 
         // Default 5 signers, including the funder.
         let signers = vec![
-            funder.pubkey(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
         ];
 
         // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
@@ -85,8 +275,11 @@ impl Tester {
         let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
 
         // Find PDAs.
-        let (state_pda, state_bump) =
-            Pubkey::find_program_address(&[b"state", (funder.pubkey().as_ref())], &pid);
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
         let (fund_pda, fund_bump) =
             Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
@@ -96,13 +289,19 @@ impl Tester {
             funder,
             recent_blockhash,
             with_signature: false,
+            readonly_queue: false,
+            without_queue: false,
             m: 3,
             signers,
             q: 10,
+            index,
             state_pda,
             state_bump,
             fund_pda,
             fund_bump,
+            forged,
+            accounts: vec![],
+            outsiders: vec![],
         }
     }
 
@@ -111,6 +310,32 @@ impl Tester {
         self
     }
 
+    fn with_readonly_queue(&mut self) -> &mut Self {
+        self.readonly_queue = true;
+        self
+    }
+
+    fn without_queue(&mut self) -> &mut Self {
+        self.without_queue = true;
+        self
+    }
+
+    fn with_account(&mut self, account: AccountMeta) -> &mut Self {
+        self.accounts.push(account);
+        self
+    }
+
+    fn with_outsider(&mut self) -> &mut Self {
+        let outsider = std::rc::Rc::new(Keypair::new());
+        self.accounts.push(AccountMeta {
+            pubkey: outsider.pubkey(),
+            is_signer: true,
+            is_writable: false,
+        });
+        self.outsiders.push(outsider);
+        self
+    }
+
     async fn get_state_account(&mut self) -> Option<multisig_lite::State> {
         self.client
             .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
@@ -138,8 +363,10 @@ impl Tester {
             })
             .args(multisig_lite::instruction::Create {
                 m: self.m,
-                signers: self.signers.clone(),
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
                 q: self.q,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -151,28 +378,113 @@ impl Tester {
         self.client.process_transaction(tx).await.unwrap();
     }
 
-    async fn close(&mut self) -> Result<(), solana_program_test::BanksClientError> {
-        // Gets the remaining transfers to collects the rents.
+    async fn fund(&mut self, lamports: u64) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_transfer(&mut self, lamports: u64) {
         let state = self.get_state_account().await.unwrap();
-        let remaining_accounts: Vec<_> = state
-            .queue
-            .into_iter()
-            .map(|pubkey| AccountMeta {
-                pubkey,
-                is_signer: false,
-                is_writable: true,
+        let (transfer, _) = Pubkey::find_program_address(
+            &[
+                b"transfer",
+                self.state_pda.as_ref(),
+                &state.transfers.to_le_bytes(),
+            ],
+            &self.program.id(),
+        );
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTransfer {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTransfer {
+                recipient: Pubkey::new_unique(),
+                lamports,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn close(
+        &mut self,
+        destination: Pubkey,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let mut remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
             })
             .collect();
 
+        // Gets the remaining transfers to collects the rents.
+        if !self.without_queue {
+            let state = self.get_state_account().await.unwrap();
+            remaining_accounts.extend(state.queue.into_iter().map(|pubkey| AccountMeta {
+                pubkey,
+                is_signer: false,
+                is_writable: !self.readonly_queue,
+            }));
+        }
+
+        // Other accounts, if any.
+        remaining_accounts.extend(self.accounts.iter().cloned());
+
         let ixs = self
             .program
             .request()
             .accounts(multisig_lite::accounts::Close {
+                signer: signer.pubkey(),
                 funder: self.funder.pubkey(),
                 state: self.state_pda,
                 fund: self.fund_pda,
+                destination,
             })
             .args(multisig_lite::instruction::Close {
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -180,9 +492,16 @@ impl Tester {
             .instructions()
             .unwrap();
 
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        signers.extend(self.outsiders.iter().map(|outsider| outsider.as_ref()));
         let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
         if self.with_signature {
-            tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+            tx.sign(&signers, self.recent_blockhash);
         }
         self.client.process_transaction(tx).await
     }
@@ -20,9 +20,8 @@ async fn create() {
     // State account.
     let state = tester.get_state_account().await;
     assert_eq!(state.m, 3);
-    assert_eq!(state.signers.len(), 5);
-    assert_eq!(state.signed.len(), 5);
-    state.signed.iter().for_each(|signed| assert!(!*signed));
+    assert_eq!(state.signers, tester.signers);
+    assert_eq!(state.weights, vec![1; 5]);
     assert_eq!(state.fund, tester.fund_pda);
     assert_eq!(state.balance, 0);
     assert_eq!(state.q, 10);
@@ -35,6 +34,24 @@ async fn create() {
     assert!(!fund.executable);
 }
 
+#[tokio::test]
+async fn create_multiple_accounts() {
+    let mut tester = Tester::new().await;
+    tester.with_signature().create().await.unwrap();
+    let first_state_pda = tester.state_pda;
+    let first_fund_pda = tester.fund_pda;
+
+    // Creates another multisig account by the same funder.
+    tester.with_index(1).create().await.unwrap();
+    assert_ne!(tester.state_pda, first_state_pda);
+    assert_ne!(tester.fund_pda, first_fund_pda);
+
+    // State account.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.m, 3);
+    assert_eq!(state.fund, tester.fund_pda);
+}
+
 #[tokio::test]
 async fn create_with_zero_threshold() {
     let err = Tester::new()
@@ -52,6 +69,88 @@ async fn create_with_zero_threshold() {
     );
 }
 
+#[tokio::test]
+async fn create_with_weights() {
+    let mut tester = Tester::new().await;
+    tester
+        .with_m(6)
+        .with_weights(vec![3, 1, 1, 1, 2])
+        .with_signature()
+        .create()
+        .await
+        .unwrap();
+
+    // Weights are kept with the signers.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.m, 6);
+    assert_eq!(state.signers, tester.signers);
+    assert_eq!(state.weights, tester.weights);
+}
+
+#[tokio::test]
+async fn create_with_unreachable_threshold() {
+    let err = Tester::new()
+        .await
+        .with_m(9)
+        .with_weights(vec![3, 1, 1, 1, 2])
+        .with_signature()
+        .create()
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6010)),
+    );
+}
+
+#[tokio::test]
+async fn create_with_mismatched_weights() {
+    let err = Tester::new()
+        .await
+        .with_weights(vec![1, 1, 1])
+        .with_signature()
+        .create()
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6027)),
+    );
+}
+
+#[tokio::test]
+async fn create_with_zero_weight() {
+    let err = Tester::new()
+        .await
+        .with_weights(vec![1, 1, 0, 1, 1])
+        .with_signature()
+        .create()
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6027)),
+    );
+}
+
+#[tokio::test]
+async fn create_with_duplicate_signers() {
+    let mut tester = Tester::new().await;
+    tester.signers[3] = tester.signers[1];
+
+    let err = tester.with_signature().create().await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6028)),
+    );
+}
+
 #[tokio::test]
 async fn create_without_signature() {
     let err = Tester::new().await.create().await.err().unwrap();
@@ -67,7 +166,9 @@ struct Tester {
     with_signature: bool,
     m: u8,
     signers: Vec<Pubkey>,
+    weights: Vec<u8>,
     q: u8,
+    index: u64,
     state_pda: Pubkey,
     state_bump: u8,
     fund_pda: Pubkey,
@@ -102,8 +203,11 @@ impl Tester {
         let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
 
         // Find PDAs.
-        let (state_pda, state_bump) =
-            Pubkey::find_program_address(&[b"state", (funder.pubkey().as_ref())], &pid);
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
         let (fund_pda, fund_bump) =
             Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
@@ -114,8 +218,10 @@ impl Tester {
             recent_blockhash,
             with_signature: false,
             m: 3,
+            weights: vec![1; signers.len()],
             signers,
             q: 10,
+            index,
             state_pda,
             state_bump,
             fund_pda,
@@ -123,11 +229,36 @@ impl Tester {
         }
     }
 
+    fn with_index(&mut self, index: u64) -> &mut Self {
+        let pid = self.program.id();
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[
+                b"state",
+                self.funder.pubkey().as_ref(),
+                &index.to_le_bytes(),
+            ],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+        self.index = index;
+        self.state_pda = state_pda;
+        self.state_bump = state_bump;
+        self.fund_pda = fund_pda;
+        self.fund_bump = fund_bump;
+        self
+    }
+
     fn with_m(&mut self, m: u8) -> &mut Self {
         self.m = m;
         self
     }
 
+    fn with_weights(&mut self, weights: Vec<u8>) -> &mut Self {
+        self.weights = weights;
+        self
+    }
+
     fn with_signature(&mut self) -> &mut Self {
         self.with_signature = true;
         self
@@ -166,7 +297,9 @@ impl Tester {
             .args(multisig_lite::instruction::Create {
                 m: self.m,
                 signers: self.signers.clone(),
+                weights: self.weights.clone(),
                 q: self.q,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -0,0 +1,278 @@
+//! `multisig_list::multisig_list::create_spending_limit` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn create_spending_limit() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Then creates a spending limit of the second signer by the 3 out of 5 signers.
+    let lamports = LAMPORTS_PER_SOL;
+    assert!(tester
+        .with_signature()
+        .create_spending_limit(1, lamports, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let limit = tester.get_limit_account().await.unwrap();
+    assert_eq!(limit.state, tester.state_pda);
+    assert_eq!(limit.signer, tester.signers[1].pubkey());
+    assert_eq!(limit.lamports, lamports);
+    assert_eq!(limit.period, multisig_lite::Period::Day);
+    assert_eq!(limit.spent, 0);
+}
+
+#[tokio::test]
+async fn create_spending_limit_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .create_spending_limit(1, LAMPORTS_PER_SOL, 0, &[1])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+    assert!(tester.get_limit_account().await.is_none());
+}
+
+#[tokio::test]
+async fn create_spending_limit_for_non_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Replaces the spender with the non-signer.
+    let outsider = Keypair::new();
+    let pid = multisig_lite::id();
+    (tester.limit_pda, _) = Pubkey::find_program_address(
+        &[
+            b"limit",
+            tester.state_pda.as_ref(),
+            outsider.pubkey().as_ref(),
+        ],
+        &pid,
+    );
+    tester.signers.push(std::rc::Rc::new(outsider));
+
+    let err = tester
+        .with_signature()
+        .create_spending_limit(5, LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6011)),
+    );
+}
+
+#[tokio::test]
+async fn create_spending_limit_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .create_spending_limit(1, LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    limit_pda: Pubkey,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        // The spending limit of the second signer.
+        let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+            &[b"limit", state_pda.as_ref(), signers[1].pubkey().as_ref()],
+            &pid,
+        );
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            limit_pda,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_limit_account(&mut self) -> Option<multisig_lite::SpendingLimit> {
+        self.client
+            .get_account_with_commitment(self.limit_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::SpendingLimit::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_spending_limit(
+        &mut self,
+        spender_index: usize,
+        lamports: u64,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateSpendingLimit {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                limit: self.limit_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateSpendingLimit {
+                pubkey: self.signers[spender_index].pubkey(),
+                lamports,
+                period: multisig_lite::Period::Day,
+            })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
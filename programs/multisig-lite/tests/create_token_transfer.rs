@@ -0,0 +1,376 @@
+//! `multisig_list::multisig_list::create_token_transfer` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+#[tokio::test]
+async fn create_token_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token accounts.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+
+    // Creates a token transfer.
+    assert!(tester
+        .with_signature()
+        .create_token_transfer(1_000)
+        .await
+        .is_ok());
+
+    // The token transfer is queued with the mint.
+    let key = tester.transfer;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.recipient, tester.recipient);
+    assert_eq!(transfer.lamports, 1_000);
+    assert_eq!(transfer.mint, Some(tester.mint.pubkey()));
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue, vec![key]);
+    assert_eq!(
+        state.pending_tokens,
+        vec![multisig_lite::PendingToken {
+            mint: tester.mint.pubkey(),
+            amount: 1_000,
+        }],
+    );
+
+    // The native SOL balance only pays the rent fee back to the creator.
+    let rent = tester.get_balance(key).await;
+    assert_eq!(state.balance, LAMPORTS_PER_SOL - rent);
+    assert_eq!(state.pending, 0);
+}
+
+#[tokio::test]
+async fn create_token_transfer_with_not_enough_balance() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token accounts.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+
+    let amount = tester.amount + 1;
+    let err = tester
+        .with_signature()
+        .create_token_transfer(amount)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6012)),
+    );
+}
+
+#[tokio::test]
+async fn create_token_transfer_over_pending_amount() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token accounts.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+
+    // Queues more than the half of the token balance.
+    let amount = tester.amount / 2 + 1;
+    assert!(tester
+        .with_signature()
+        .create_token_transfer(amount)
+        .await
+        .is_ok());
+
+    // Then tries another one, which exceeds the balance together.
+    tester.transfer = tester.transfer_pda(1);
+    let err = tester.create_token_transfer(amount).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6012)),
+    );
+}
+
+#[tokio::test]
+async fn create_token_transfer_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token accounts.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+
+    let err = tester.create_token_transfer(1_000).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    mint: Keypair,
+    amount: u64,
+    transfer: Pubkey,
+    recipient: Pubkey,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let mut program_test = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        );
+
+        // Runs the token programs as native code, as the multisig program.
+        program_test.add_program(
+            "spl_token",
+            spl_token::id(),
+            solana_program_test::processor!(spl_token::processor::Processor::process),
+        );
+        program_test.add_program(
+            "spl_associated_token_account",
+            spl_associated_token_account::id(),
+            solana_program_test::processor!(
+                spl_associated_token_account::processor::process_instruction
+            ),
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+        let (transfer, _) = Pubkey::find_program_address(
+            &[b"transfer", state_pda.as_ref(), &0u64.to_le_bytes()],
+            &pid,
+        );
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            mint: Keypair::new(),
+            amount: 1_000_000,
+            transfer,
+            recipient: Pubkey::new_unique(),
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
+        self.client
+            .get_account_with_commitment(key, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    fn transfer_pda(&self, index: u64) -> Pubkey {
+        let (transfer_pda, _) = Pubkey::find_program_address(
+            &[b"transfer", self.state_pda.as_ref(), &index.to_le_bytes()],
+            &self.program.id(),
+        );
+        transfer_pda
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: LAMPORTS_PER_SOL,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_token_accounts(&mut self) {
+        let funder = self.funder.pubkey();
+        let mint = self.mint.pubkey();
+        let fund_token = get_associated_token_address(&self.fund_pda, &mint);
+        let rent = self.client.get_rent().await.unwrap();
+        let mint_len = spl_token::state::Mint::LEN;
+
+        // Creates a mint, the multisig fund and the recipient
+        // associated token accounts, and mints the tokens to
+        // the multisig fund.
+        let ixs = [
+            system_instruction::create_account(
+                &funder,
+                &mint,
+                rent.minimum_balance(mint_len),
+                mint_len as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint(&spl_token::id(), &mint, &funder, None, 0)
+                .unwrap(),
+            create_associated_token_account(&funder, &self.fund_pda, &mint, &spl_token::id()),
+            create_associated_token_account(&funder, &self.recipient, &mint, &spl_token::id()),
+            spl_token::instruction::mint_to(
+                &spl_token::id(),
+                &mint,
+                &fund_token,
+                &funder,
+                &[],
+                self.amount,
+            )
+            .unwrap(),
+        ];
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&funder));
+        tx.sign(&[self.funder.as_ref(), &self.mint], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_token_transfer(
+        &mut self,
+        amount: u64,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let mint = self.mint.pubkey();
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTokenTransfer {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                mint,
+                fund_token: get_associated_token_address(&self.fund_pda, &mint),
+                transfer: self.transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTokenTransfer {
+                recipient: self.recipient,
+                amount,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
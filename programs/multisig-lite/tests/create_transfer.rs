@@ -1,6 +1,8 @@
 //! `multisig_list::multisig_list::create_transfer` instruction tests.
 
+use solana_sdk::commitment_config::CommitmentLevel;
 use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::keypair::Keypair;
@@ -8,6 +10,8 @@ use solana_sdk::signer::Signer;
 use solana_sdk::system_program;
 use solana_sdk::transaction::{Transaction, TransactionError};
 
+use anchor_client::anchor_lang::AccountDeserialize;
+
 #[tokio::test]
 async fn create_transfer() {
     let mut tester = Tester::new().await;
@@ -17,14 +21,138 @@ async fn create_transfer() {
     tester.fund().await;
 
     // Then creates a transfer.
-    let transfer = Keypair::new();
+    let transfer = tester.transfer_pda(0);
     let recipient = Pubkey::new_unique();
     let lamports = 2_000 * LAMPORTS_PER_SOL;
     assert!(tester
         .with_signature()
-        .create_transfer(&transfer, recipient, lamports)
+        .create_transfer(transfer, recipient, lamports)
+        .await
+        .is_ok());
+
+    // Transfer account.
+    let account = tester.get_transfer_account(transfer).await.unwrap();
+    assert_eq!(account.creator, tester.funder.pubkey());
+    assert_eq!(account.recipient, recipient);
+    assert_eq!(account.lamports, lamports);
+    assert_eq!(account.mint, None);
+    assert_eq!(account.memo, None);
+
+    // The rent fee given back to the creator comes out of the balance.
+    let rent = tester.get_balance(transfer).await;
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, tester.lamports - lamports - rent);
+    assert_eq!(state.pending, lamports);
+}
+
+#[tokio::test]
+async fn create_transfer_with_memo() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds the  multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    // Then creates a transfer with the invoice reference.
+    let transfer = tester.transfer_pda(0);
+    let recipient = Pubkey::new_unique();
+    let lamports = 2_000 * LAMPORTS_PER_SOL;
+    assert!(tester
+        .with_memo("Invoice #1234")
+        .with_signature()
+        .create_transfer(transfer, recipient, lamports)
+        .await
+        .is_ok());
+
+    // The memo is kept in the transfer account.
+    let account = tester.get_transfer_account(transfer).await.unwrap();
+    assert_eq!(account.memo, Some("Invoice #1234".to_string()));
+}
+
+#[tokio::test]
+async fn create_transfers_with_sequential_addresses() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds the multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    // Each transfer takes the next index of the multisig account.
+    let first = tester.transfer_pda(0);
+    let second = tester.transfer_pda(1);
+    let lamports = 1_000 * LAMPORTS_PER_SOL;
+    assert!(tester
+        .with_signature()
+        .create_transfer(first, Pubkey::new_unique(), lamports)
         .await
         .is_ok());
+    assert!(tester
+        .create_transfer(second, Pubkey::new_unique(), lamports)
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.transfers, 2);
+    assert_eq!(state.queue, vec![first, second]);
+
+    // The already used address can't be reused.
+    let err = tester
+        .create_transfer(first, Pubkey::new_unique(), lamports)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(2006)),
+    );
+}
+
+#[tokio::test]
+async fn create_transfer_with_too_long_memo() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds the  multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    let transfer = tester.transfer_pda(0);
+    let recipient = Pubkey::new_unique();
+    let lamports = 2_000 * LAMPORTS_PER_SOL;
+    let memo = "x".repeat(multisig_lite::Transfer::MAX_MEMO + 1);
+    let err = tester
+        .with_memo(&memo)
+        .with_signature()
+        .create_transfer(transfer, recipient, lamports)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6032)),
+    );
+}
+
+#[tokio::test]
+async fn create_transfer_without_rent_fee() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds the multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    // The whole balance leaves nothing for the transfer account rent fee.
+    let transfer = tester.transfer_pda(0);
+    let lamports = tester.lamports;
+    let err = tester
+        .with_signature()
+        .create_transfer(transfer, Pubkey::new_unique(), lamports)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6012)),
+    );
 }
 
 #[tokio::test]
@@ -35,11 +163,11 @@ async fn create_transfer_without_signature() {
     tester.create().await;
     tester.fund().await;
 
-    let transfer = Keypair::new();
+    let transfer = tester.transfer_pda(0);
     let recipient = Pubkey::new_unique();
     let lamports = 10_000 * LAMPORTS_PER_SOL;
     let err = tester
-        .create_transfer(&transfer, recipient, lamports)
+        .create_transfer(transfer, recipient, lamports)
         .await
         .err()
         .unwrap();
@@ -55,11 +183,13 @@ struct Tester {
     m: u8,
     signers: Vec<Pubkey>,
     q: u8,
+    index: u64,
     state_pda: Pubkey,
     state_bump: u8,
     fund_pda: Pubkey,
     fund_bump: u8,
     lamports: u64,
+    memo: Option<String>,
 }
 
 impl Tester {
@@ -90,8 +220,11 @@ impl Tester {
         let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
 
         // Find PDAs.
-        let (state_pda, state_bump) =
-            Pubkey::find_program_address(&[b"state", (funder.pubkey().as_ref())], &pid);
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
         let (fund_pda, fund_bump) =
             Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
@@ -104,19 +237,61 @@ impl Tester {
             m: 3,
             signers,
             q: 10,
+            index,
             state_pda,
             state_bump,
             fund_pda,
             fund_bump,
             lamports: 100_000 * LAMPORTS_PER_SOL, // 100k SOL!? :)
+            memo: None,
         }
     }
 
+    fn with_memo(&mut self, memo: &str) -> &mut Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
     fn with_signature(&mut self) -> &mut Self {
         self.with_signature = true;
         self
     }
 
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
+        self.client
+            .get_account_with_commitment(key, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    fn transfer_pda(&self, index: u64) -> Pubkey {
+        let (transfer_pda, _) = Pubkey::find_program_address(
+            &[b"transfer", self.state_pda.as_ref(), &index.to_le_bytes()],
+            &self.program.id(),
+        );
+        transfer_pda
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
+    }
+
     async fn create(&mut self) {
         let ixs = self
             .program
@@ -130,7 +305,9 @@ impl Tester {
             .args(multisig_lite::instruction::Create {
                 m: self.m,
                 signers: self.signers.clone(),
+                weights: vec![1; self.signers.len()],
                 q: self.q,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -154,6 +331,7 @@ impl Tester {
             })
             .args(multisig_lite::instruction::Fund {
                 lamports: self.lamports,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -167,7 +345,7 @@ impl Tester {
 
     async fn create_transfer(
         &mut self,
-        transfer: &Keypair,
+        transfer: Pubkey,
         recipient: Pubkey,
         lamports: u64,
     ) -> Result<(), solana_program_test::BanksClientError> {
@@ -178,12 +356,15 @@ impl Tester {
                 creator: self.funder.pubkey(),
                 state: self.state_pda,
                 fund: self.fund_pda,
-                transfer: transfer.pubkey(),
+                transfer,
                 system_program: system_program::id(),
             })
             .args(multisig_lite::instruction::CreateTransfer {
                 recipient,
                 lamports,
+                execute_after: None,
+                expire_at: None,
+                memo: self.memo.clone(),
                 fund_bump: self.fund_bump,
             })
             .instructions()
@@ -191,7 +372,7 @@ impl Tester {
 
         let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
         if self.with_signature {
-            tx.sign(&[self.funder.as_ref(), transfer], self.recent_blockhash);
+            tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
         }
         self.client.process_transaction(tx).await
     }
@@ -0,0 +1,218 @@
+//! `multisig_list::multisig_list::deposit` instruction tests.
+
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn deposit() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Current fund account lamports.
+    let fund_lamports = tester.get_fund_account().await.unwrap().lamports;
+
+    // Then deposit from the non-funder payer.
+    assert!(tester
+        .with_signature()
+        .deposit(10 * LAMPORTS_PER_SOL)
+        .await
+        .is_ok());
+
+    // Both the state balance and the fund lamports are credited.
+    let state = tester.get_state_account().await.unwrap();
+    assert_eq!(state.balance, 10 * LAMPORTS_PER_SOL);
+    let fund = tester.get_fund_account().await.unwrap();
+    assert_eq!(fund.lamports, fund_lamports + 10 * LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn deposit_twice() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Deposit twice.
+    tester.with_signature();
+    assert!(tester.deposit(2 * LAMPORTS_PER_SOL).await.is_ok());
+    assert!(tester.deposit(3 * LAMPORTS_PER_SOL).await.is_ok());
+
+    let state = tester.get_state_account().await.unwrap();
+    assert_eq!(state.balance, 5 * LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn deposit_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester.deposit(2 * LAMPORTS_PER_SOL).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    payer: Keypair,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<Pubkey>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.pubkey(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        Self {
+            program,
+            client,
+            funder,
+            payer: Keypair::new(),
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> Option<multisig_lite::State> {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn get_fund_account(&mut self) -> Option<Account> {
+        self.client.get_account(self.fund_pda).await.unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.clone(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn deposit(
+        &mut self,
+        lamports: u64,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        // Funds the payer first, as it's a brand new account.
+        let mut ixs = vec![system_instruction::transfer(
+            &self.funder.pubkey(),
+            &self.payer.pubkey(),
+            lamports + LAMPORTS_PER_SOL,
+        )];
+        ixs.extend(
+            self.program
+                .request()
+                .accounts(multisig_lite::accounts::Deposit {
+                    payer: self.payer.pubkey(),
+                    state: self.state_pda,
+                    fund: self.fund_pda,
+                    system_program: system_program::id(),
+                })
+                .args(multisig_lite::instruction::Deposit {
+                    lamports,
+                    fund_bump: self.fund_bump,
+                })
+                .instructions()
+                .unwrap(),
+        );
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&[self.funder.as_ref(), &self.payer], self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
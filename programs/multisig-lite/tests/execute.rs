@@ -0,0 +1,584 @@
+//! `multisig_list::multisig_list::execute` instruction tests.
+
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn execute() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    // Then executes it.
+    assert!(tester.execute(1).await.is_ok());
+
+    // The transfer is executed and removed from the queue.
+    let key = tester.transfers[1].transfer;
+    let recipient = tester.transfers[1].recipient;
+    let lamports = tester.transfers[1].lamports;
+    assert!(tester.get_transfer_account(key).await.is_none());
+    assert_eq!(tester.get_balance(recipient).await, lamports);
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue.len(), tester.transfers.len() - 1);
+    assert!(!state.queue.contains(&key));
+}
+
+#[tokio::test]
+async fn execute_without_enough_approvals() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the m - 1 signers.
+    for signer in 0..tester.m as usize - 1 {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    let err = tester.execute(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn execute_with_weighted_approvals() {
+    let mut tester = Tester::new().await;
+
+    // Creates the multisig account, the funder vote counts as three.
+    tester.weights = vec![3, 1, 1, 1, 1];
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the two signers other than the funder.
+    for signer in 1..3 {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+    let err = tester.execute(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+
+    // The funder approval alone meets the threshold.
+    assert!(tester.with_signature().approve(0, 2).await.is_ok());
+    assert!(tester.execute(2).await.is_ok());
+
+    // The transfer is executed and removed from the queue.
+    let key = tester.transfers[2].transfer;
+    let recipient = tester.transfers[2].recipient;
+    let lamports = tester.transfers[2].lamports;
+    assert!(tester.get_transfer_account(key).await.is_none());
+    assert_eq!(tester.get_balance(recipient).await, lamports);
+}
+
+#[tokio::test]
+async fn execute_transfer_with_memo() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a transfer with the memo on the multisig account.
+    tester.transfers[1].memo = Some("Invoice #1234".to_string());
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the transfer by the m signers and executes it.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+    assert!(tester.execute(1).await.is_ok());
+
+    // The memo is recorded by the memo program.
+    assert!(tester
+        .logs
+        .iter()
+        .any(|log| log.contains("Memo") && log.contains("Invoice #1234")));
+}
+
+#[tokio::test]
+async fn execute_time_locked_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates a time-locked transfer on the multisig account.
+    let now = tester.get_unix_timestamp().await;
+    tester.transfers[1].execute_after = Some(now + 3600);
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the time-locked transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    let err = tester.execute(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6021)),
+    );
+
+    // The transfer stays in the queue.
+    let key = tester.transfers[1].transfer;
+    let recipient = tester.transfers[1].recipient;
+    assert_eq!(tester.get_balance(recipient).await, 0);
+    let state = tester.get_state_account().await;
+    assert!(state.queue.contains(&key));
+}
+
+#[tokio::test]
+async fn execute_unlocked_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates an already unlocked transfer on the multisig account.
+    let now = tester.get_unix_timestamp().await;
+    tester.transfers[1].execute_after = Some(now - 1);
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the transfer by the m signers and executes it.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+    assert!(tester.execute(1).await.is_ok());
+
+    // The transfer is executed and removed from the queue.
+    let key = tester.transfers[1].transfer;
+    let recipient = tester.transfers[1].recipient;
+    let lamports = tester.transfers[1].lamports;
+    assert!(tester.get_transfer_account(key).await.is_none());
+    assert_eq!(tester.get_balance(recipient).await, lamports);
+    let state = tester.get_state_account().await;
+    assert!(!state.queue.contains(&key));
+}
+
+#[tokio::test]
+async fn execute_with_invalid_recipient() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    // Tries to redirect the transfer to the other recipient.
+    tester.transfers[1].recipient = Pubkey::new_unique();
+    let err = tester.execute(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6014)),
+    );
+}
+
+#[tokio::test]
+async fn execute_and_reuse_closed_transfer() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the second transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.with_signature().approve(signer, 1).await.is_ok());
+    }
+
+    // Executes it and tries to revive the closed transfer account
+    // in the same transaction.
+    let err = tester.execute_and_reuse(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(2, InstructionError::Custom(3007)),
+    );
+
+    // The transfer is still queued.
+    let key = tester.transfers[1].transfer;
+    assert!(tester.get_transfer_account(key).await.is_some());
+    let state = tester.get_state_account().await;
+    assert!(state.queue.contains(&key));
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    weights: Vec<u8>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+    transfers: Vec<TestTransfer>,
+    logs: Vec<String>,
+}
+
+struct TestTransfer {
+    transfer: Pubkey,
+    recipient: Pubkey,
+    lamports: u64,
+    execute_after: Option<i64>,
+    expire_at: Option<i64>,
+    memo: Option<String>,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let mut program_test = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        );
+
+        // Runs the memo program as native code, as the multisig program.
+        program_test.add_program(
+            "spl_memo",
+            spl_memo::id(),
+            solana_program_test::processor!(spl_memo::processor::process_instruction),
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        // Transfers.
+        let mut transfers = vec![];
+        (0..10).for_each(|i| {
+            let (transfer, _) = Pubkey::find_program_address(
+                &[b"transfer", state_pda.as_ref(), &(i as u64).to_le_bytes()],
+                &pid,
+            );
+            transfers.push(TestTransfer {
+                transfer,
+                recipient: Pubkey::new_unique(),
+                lamports: i as u64 * LAMPORTS_PER_SOL,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+            })
+        });
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            weights: vec![1; signers.len()],
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 100_000 * LAMPORTS_PER_SOL, // 100k SOL!? :)
+            transfers,
+            logs: vec![],
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
+        self.client
+            .get_account_with_commitment(key, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn get_unix_timestamp(&mut self) -> i64 {
+        self.client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: self.weights.clone(),
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_transfers(&mut self) {
+        let mut ixs = vec![];
+        self.transfers.iter().for_each(|transfer| {
+            ixs.extend(
+                self.program
+                    .request()
+                    .accounts(multisig_lite::accounts::CreateTransfer {
+                        creator: self.funder.pubkey(),
+                        state: self.state_pda,
+                        fund: self.fund_pda,
+                        transfer: transfer.transfer,
+                        system_program: system_program::id(),
+                    })
+                    .args(multisig_lite::instruction::CreateTransfer {
+                        recipient: transfer.recipient,
+                        lamports: transfer.lamports,
+                        execute_after: transfer.execute_after,
+                        expire_at: transfer.expire_at,
+                        memo: transfer.memo.clone(),
+                        fund_bump: self.fund_bump,
+                    })
+                    .instructions()
+                    .unwrap(),
+            );
+        });
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn approve(
+        &mut self,
+        signer_index: usize,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let transfer = &self.transfers[transfer_index];
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Approve {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                transfer: transfer.transfer,
+            })
+            .args(multisig_lite::instruction::Approve {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+
+    async fn execute(
+        &mut self,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let transfer = &self.transfers[transfer_index];
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Execute {
+                executor: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: transfer.transfer,
+                recipient: transfer.recipient,
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::Execute {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.process_transaction(tx).await
+    }
+
+    async fn execute_and_reuse(
+        &mut self,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let rent = self
+            .get_balance(self.transfers[transfer_index].transfer)
+            .await;
+        let transfer = &self.transfers[transfer_index];
+
+        let mut ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Execute {
+                executor: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: transfer.transfer,
+                recipient: transfer.recipient,
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::Execute {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        // Gives the rent back to the closed transfer account.
+        ixs.push(system_instruction::transfer(
+            &self.funder.pubkey(),
+            &transfer.transfer,
+            rent,
+        ));
+
+        // Then approves it again.
+        ixs.extend(
+            self.program
+                .request()
+                .accounts(multisig_lite::accounts::Approve {
+                    signer: self.funder.pubkey(),
+                    state: self.state_pda,
+                    transfer: transfer.transfer,
+                })
+                .args(multisig_lite::instruction::Approve {})
+                .instructions()
+                .unwrap(),
+        );
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.process_transaction(tx).await
+    }
+
+    async fn process_transaction(
+        &mut self,
+        tx: Transaction,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        // Keeps the program logs to check the recorded memo.
+        let simulation = self.client.simulate_transaction(tx.clone()).await?;
+        self.logs = simulation
+            .simulation_details
+            .map(|details| details.logs)
+            .unwrap_or_default();
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,336 @@
+//! `multisig_list::multisig_list::execute_proposal` instruction tests.
+
+use std::str::FromStr;
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+#[tokio::test]
+async fn execute_proposal() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with a proposal.
+    tester.create().await;
+    tester.fund().await;
+    tester.with_signature().create_proposal(0).await.unwrap();
+
+    // Approves the proposal by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.approve_proposal(signer).await.is_ok());
+    }
+    let proposal = tester.get_proposal_account().await.unwrap();
+    assert_eq!(proposal.signed, vec![true, true, true, false, false]);
+
+    // Executes the proposal signed by the multisig fund PDA.
+    assert!(tester.execute_proposal().await.is_ok());
+
+    // The proposal is closed and removed from the queue.
+    assert!(tester.get_proposal_account().await.is_none());
+    let state = tester.get_state_account().await;
+    assert!(state.queue.is_empty());
+}
+
+#[tokio::test]
+async fn execute_proposal_without_enough_approvals() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with a proposal.
+    tester.create().await;
+    tester.fund().await;
+    tester.with_signature().create_proposal(0).await.unwrap();
+
+    // Approves the proposal by the m - 1 signers.
+    for signer in 0..tester.m as usize - 1 {
+        assert!(tester.approve_proposal(signer).await.is_ok());
+    }
+
+    let err = tester.execute_proposal().await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+
+    // The proposal is still queued.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue, vec![tester.proposal.pubkey()]);
+}
+
+#[tokio::test]
+async fn approve_proposal_by_non_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with a proposal.
+    tester.create().await;
+    tester.fund().await;
+    tester.with_signature().create_proposal(0).await.unwrap();
+
+    // Replaces the signer with the non multisig signer.
+    tester.signers[1] = std::rc::Rc::new(Keypair::new());
+    let err = tester.approve_proposal(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6011)),
+    );
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    proposal: Keypair,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let mut program_test = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        );
+
+        // Runs the memo program as native code, as the multisig program.
+        program_test.add_program(
+            "spl_memo",
+            spl_memo::id(),
+            solana_program_test::processor!(spl_memo::processor::process_instruction),
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            proposal: Keypair::new(),
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_proposal_account(&mut self) -> Option<multisig_lite::Proposal> {
+        self.client
+            .get_account_with_commitment(self.proposal.pubkey(), CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Proposal::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: LAMPORTS_PER_SOL,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_proposal(
+        &mut self,
+        creator_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let creator = self.signers[creator_index].clone();
+
+        // The memo instruction signed by the multisig fund PDA.
+        let memo = Pubkey::from_str(MEMO_PROGRAM_ID).unwrap();
+        let keys = vec![multisig_lite::ProposalAccount {
+            pubkey: self.fund_pda,
+            is_signer: true,
+            is_writable: false,
+        }];
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateProposal {
+                creator: creator.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                proposal: self.proposal.pubkey(),
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateProposal {
+                program: memo,
+                keys,
+                data: b"Hello from the multisig".to_vec(),
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref(), &self.proposal];
+        if creator.pubkey() != self.funder.pubkey() {
+            signers.push(creator.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+
+    async fn approve_proposal(
+        &mut self,
+        signer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ApproveProposal {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                proposal: self.proposal.pubkey(),
+            })
+            .args(multisig_lite::instruction::ApproveProposal {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn execute_proposal(&mut self) -> Result<(), solana_program_test::BanksClientError> {
+        let memo = Pubkey::from_str(MEMO_PROGRAM_ID).unwrap();
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ExecuteProposal {
+                executor: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                proposal: self.proposal.pubkey(),
+            })
+            .args(multisig_lite::instruction::ExecuteProposal {
+                fund_bump: self.fund_bump,
+            })
+            .accounts(vec![AccountMeta::new_readonly(memo, false)])
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,470 @@
+//! `multisig_list::multisig_list::execute_token` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+#[tokio::test]
+async fn execute_token() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token transfer.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+    tester.create_token_transfer().await;
+
+    // Approves the token transfer by the m signers and executes it.
+    for signer in 0..tester.m as usize {
+        assert!(tester.approve(signer).await.is_ok());
+    }
+    assert!(tester.execute_token().await.is_ok());
+
+    // The tokens are transferred and the transfer is removed from the queue.
+    let key = tester.transfer;
+    assert!(tester.get_transfer_account(key).await.is_none());
+    let recipient_token = get_associated_token_address(&tester.recipient, &tester.mint.pubkey());
+    let fund_token = get_associated_token_address(&tester.fund_pda, &tester.mint.pubkey());
+    assert_eq!(
+        tester.get_token_amount(recipient_token).await,
+        tester.transfer_amount
+    );
+    assert_eq!(
+        tester.get_token_amount(fund_token).await,
+        tester.amount - tester.transfer_amount
+    );
+    let state = tester.get_state_account().await;
+    assert!(state.queue.is_empty());
+    assert!(state.pending_tokens.is_empty());
+}
+
+#[tokio::test]
+async fn execute_token_with_memo() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token transfer with the memo.
+    tester.memo = Some("Invoice #1234".to_string());
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+    tester.create_token_transfer().await;
+
+    // Approves the token transfer by the m signers and executes it.
+    for signer in 0..tester.m as usize {
+        assert!(tester.approve(signer).await.is_ok());
+    }
+    assert!(tester.execute_token().await.is_ok());
+
+    // The memo is recorded by the memo program.
+    assert!(tester
+        .logs
+        .iter()
+        .any(|log| log.contains("Memo") && log.contains("Invoice #1234")));
+}
+
+#[tokio::test]
+async fn execute_token_without_enough_approvals() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token transfer.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+    tester.create_token_transfer().await;
+
+    // Approves the token transfer by a signer.
+    assert!(tester.approve(0).await.is_ok());
+
+    let err = tester.execute_token().await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+
+    // The transfer is still queued.
+    let key = tester.transfer;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.signed, vec![true, false, false, false, false]);
+    let state = tester.get_state_account().await;
+    assert_eq!(state.queue, vec![key]);
+}
+
+#[tokio::test]
+async fn execute_token_transfer_with_execute() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the token transfer.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_token_accounts().await;
+    tester.create_token_transfer().await;
+
+    // Approves the token transfer by the m signers.
+    for signer in 0..tester.m as usize {
+        assert!(tester.approve(signer).await.is_ok());
+    }
+
+    // The native SOL execute rejects the token transfer.
+    let err = tester.execute().await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6017)),
+    );
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    mint: Keypair,
+    amount: u64,
+    transfer: Pubkey,
+    recipient: Pubkey,
+    transfer_amount: u64,
+    memo: Option<String>,
+    logs: Vec<String>,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let mut program_test = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        );
+
+        // Runs the token and memo programs as native code, as the multisig program.
+        program_test.add_program(
+            "spl_token",
+            spl_token::id(),
+            solana_program_test::processor!(spl_token::processor::Processor::process),
+        );
+        program_test.add_program(
+            "spl_associated_token_account",
+            spl_associated_token_account::id(),
+            solana_program_test::processor!(
+                spl_associated_token_account::processor::process_instruction
+            ),
+        );
+        program_test.add_program(
+            "spl_memo",
+            spl_memo::id(),
+            solana_program_test::processor!(spl_memo::processor::process_instruction),
+        );
+        let (client, funder, recent_blockhash) = program_test.start().await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+        let (transfer, _) = Pubkey::find_program_address(
+            &[b"transfer", state_pda.as_ref(), &0u64.to_le_bytes()],
+            &pid,
+        );
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            mint: Keypair::new(),
+            amount: 1_000_000,
+            transfer,
+            recipient: Pubkey::new_unique(),
+            transfer_amount: 1_000,
+            memo: None,
+            logs: vec![],
+        }
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
+        self.client
+            .get_account_with_commitment(key, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn get_token_amount(&mut self, key: Pubkey) -> u64 {
+        let account = self.client.get_account(key).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: LAMPORTS_PER_SOL,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_token_accounts(&mut self) {
+        let funder = self.funder.pubkey();
+        let mint = self.mint.pubkey();
+        let fund_token = get_associated_token_address(&self.fund_pda, &mint);
+        let rent = self.client.get_rent().await.unwrap();
+        let mint_len = spl_token::state::Mint::LEN;
+
+        // Creates a mint, the multisig fund and the recipient
+        // associated token accounts, and mints the tokens to
+        // the multisig fund.
+        let ixs = [
+            system_instruction::create_account(
+                &funder,
+                &mint,
+                rent.minimum_balance(mint_len),
+                mint_len as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint(&spl_token::id(), &mint, &funder, None, 0)
+                .unwrap(),
+            create_associated_token_account(&funder, &self.fund_pda, &mint, &spl_token::id()),
+            create_associated_token_account(&funder, &self.recipient, &mint, &spl_token::id()),
+            spl_token::instruction::mint_to(
+                &spl_token::id(),
+                &mint,
+                &fund_token,
+                &funder,
+                &[],
+                self.amount,
+            )
+            .unwrap(),
+        ];
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&funder));
+        tx.sign(&[self.funder.as_ref(), &self.mint], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_token_transfer(&mut self) {
+        let mint = self.mint.pubkey();
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTokenTransfer {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                mint,
+                fund_token: get_associated_token_address(&self.fund_pda, &mint),
+                transfer: self.transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTokenTransfer {
+                recipient: self.recipient,
+                amount: self.transfer_amount,
+                execute_after: None,
+                expire_at: None,
+                memo: self.memo.clone(),
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn approve(
+        &mut self,
+        signer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Approve {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                transfer: self.transfer,
+            })
+            .args(multisig_lite::instruction::Approve {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn execute(&mut self) -> Result<(), solana_program_test::BanksClientError> {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Execute {
+                executor: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: self.transfer,
+                recipient: self.recipient,
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::Execute {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn execute_token(&mut self) -> Result<(), solana_program_test::BanksClientError> {
+        let mint = self.mint.pubkey();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ExecuteToken {
+                executor: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: self.transfer,
+                mint,
+                fund_token: get_associated_token_address(&self.fund_pda, &mint),
+                recipient_token: get_associated_token_address(&self.recipient, &mint),
+                token_program: spl_token::id(),
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::ExecuteToken {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.process_transaction(tx).await
+    }
+
+    async fn process_transaction(
+        &mut self,
+        tx: Transaction,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        // Keeps the program logs to check the recorded memo.
+        let simulation = self.client.simulate_transaction(tx.clone()).await?;
+        self.logs = simulation
+            .simulation_details
+            .map(|details| details.logs)
+            .unwrap_or_default();
+        self.client.process_transaction(tx).await
+    }
+}
@@ -29,6 +29,10 @@ async fn fund() {
         .fund(10 * LAMPORTS_PER_SOL)
         .await
         .is_ok());
+
+    // The funded lamports are tracked in the fund balance.
+    let state = tester.get_state_account().await.unwrap();
+    assert_eq!(state.balance, 10 * LAMPORTS_PER_SOL);
 }
 
 #[tokio::test]
@@ -51,6 +55,7 @@ struct Tester {
     m: u8,
     signers: Vec<Pubkey>,
     q: u8,
+    index: u64,
     state_pda: Pubkey,
     state_bump: u8,
     fund_pda: Pubkey,
@@ -85,8 +90,11 @@ impl Tester {
         let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
 
         // Find PDAs.
-        let (state_pda, state_bump) =
-            Pubkey::find_program_address(&[b"state", (funder.pubkey().as_ref())], &pid);
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
         let (fund_pda, fund_bump) =
             Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
 
@@ -99,6 +107,7 @@ impl Tester {
             m: 3,
             signers,
             q: 10,
+            index,
             state_pda,
             state_bump,
             fund_pda,
@@ -139,7 +148,9 @@ impl Tester {
             .args(multisig_lite::instruction::Create {
                 m: self.m,
                 signers: self.signers.clone(),
+                weights: vec![1; self.signers.len()],
                 q: self.q,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -163,6 +174,7 @@ impl Tester {
             })
             .args(multisig_lite::instruction::Fund {
                 lamports,
+                _index: self.index,
                 _state_bump: self.state_bump,
                 fund_bump: self.fund_bump,
             })
@@ -0,0 +1,157 @@
+//! `multisig_list::multisig_list::resize_queue` instruction tests.
+
+mod common;
+
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use common::Tester;
+
+#[tokio::test]
+async fn resize_queue() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+    let len = tester.get_state_data_len().await;
+
+    // Then grows the queue by the 3 out of 5 signers.
+    assert!(tester
+        .with_signature()
+        .resize_queue(20, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.q, 20);
+    assert_eq!(tester.get_state_data_len().await, len + (32 + 40) * 10);
+}
+
+#[tokio::test]
+async fn resize_queue_to_shrink() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates two transfers on the multisig account.
+    tester.create().await;
+    tester.fund(LAMPORTS_PER_SOL).await;
+    tester.create_transfer(LAMPORTS_PER_SOL / 2).await;
+    tester.create_transfer(LAMPORTS_PER_SOL / 4).await;
+    let len = tester.get_state_data_len().await;
+
+    // Then shrinks the queue to the pending transfers.
+    assert!(tester
+        .with_signature()
+        .resize_queue(2, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(state.q, 2);
+    assert_eq!(state.queue.len(), 2);
+    assert_eq!(tester.get_state_data_len().await, len - (32 + 40) * 8);
+}
+
+#[tokio::test]
+async fn resize_queue_below_pending_transfers() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates two transfers on the multisig account.
+    tester.create().await;
+    tester.fund(LAMPORTS_PER_SOL).await;
+    tester.create_transfer(LAMPORTS_PER_SOL / 2).await;
+    tester.create_transfer(LAMPORTS_PER_SOL / 4).await;
+
+    let err = tester
+        .with_signature()
+        .resize_queue(1, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6029)),
+    );
+}
+
+#[tokio::test]
+async fn resize_queue_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .resize_queue(20, 0, &[1])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn resize_queue_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester.resize_queue(20, 0, &[1, 2]).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+impl Tester {
+    async fn resize_queue(
+        &mut self,
+        q: u8,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::ResizeQueue {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::ResizeQueue { q })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,327 @@
+//! `multisig_list::multisig_list::revoke` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn revoke() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the first transfer by the second signer.
+    tester.with_signature().approve(1, 0).await.unwrap();
+    let key = tester.transfers[0].transfer;
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert_eq!(transfer.signed, vec![false, true, false, false, false]);
+
+    // Then revokes it.
+    assert!(tester.with_signature().revoke(1, 0).await.is_ok());
+    let transfer = tester.get_transfer_account(key).await.unwrap();
+    assert!(transfer.signed.iter().all(|signed| !*signed));
+}
+
+#[tokio::test]
+async fn revoke_without_approval() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+
+    // Approves the first transfer by the second signer.
+    tester.with_signature().approve(1, 0).await.unwrap();
+
+    // The third signer has nothing to revoke.
+    let err = tester.with_signature().revoke(2, 0).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6016)),
+    );
+}
+
+#[tokio::test]
+async fn revoke_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates, funds, and creates transfers on the multisig account.
+    tester.create().await;
+    tester.fund().await;
+    tester.create_transfers().await;
+    tester.with_signature().approve(0, 0).await.unwrap();
+
+    let err = tester.without_signature().revoke(0, 0).await.err().unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+    transfers: Vec<TestTransfer>,
+}
+
+struct TestTransfer {
+    transfer: Pubkey,
+    recipient: Pubkey,
+    lamports: u64,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        // Transfers.
+        let mut transfers = vec![];
+        (0..10).for_each(|i| {
+            let (transfer, _) = Pubkey::find_program_address(
+                &[b"transfer", state_pda.as_ref(), &(i as u64).to_le_bytes()],
+                &pid,
+            );
+            transfers.push(TestTransfer {
+                transfer,
+                recipient: Pubkey::new_unique(),
+                lamports: i as u64 * LAMPORTS_PER_SOL,
+            })
+        });
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 100_000 * LAMPORTS_PER_SOL, // 100k SOL!? :)
+            transfers,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    fn without_signature(&mut self) -> &mut Self {
+        self.with_signature = false;
+        self
+    }
+
+    async fn get_transfer_account(&mut self, key: Pubkey) -> Option<multisig_lite::Transfer> {
+        self.client
+            .get_account_with_commitment(key, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::Transfer::try_deserialize(&mut data).unwrap()
+            })
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_transfers(&mut self) {
+        let mut ixs = vec![];
+        self.transfers.iter().for_each(|transfer| {
+            ixs.extend(
+                self.program
+                    .request()
+                    .accounts(multisig_lite::accounts::CreateTransfer {
+                        creator: self.funder.pubkey(),
+                        state: self.state_pda,
+                        fund: self.fund_pda,
+                        transfer: transfer.transfer,
+                        system_program: system_program::id(),
+                    })
+                    .args(multisig_lite::instruction::CreateTransfer {
+                        recipient: transfer.recipient,
+                        lamports: transfer.lamports,
+                        execute_after: None,
+                        expire_at: None,
+                        memo: None,
+                        fund_bump: self.fund_bump,
+                    })
+                    .instructions()
+                    .unwrap(),
+            );
+        });
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn approve(
+        &mut self,
+        signer_index: usize,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let transfer = &self.transfers[transfer_index];
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Approve {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                transfer: transfer.transfer,
+            })
+            .args(multisig_lite::instruction::Approve {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+
+    async fn revoke(
+        &mut self,
+        signer_index: usize,
+        transfer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+        let transfer = &self.transfers[transfer_index];
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Revoke {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                transfer: transfer.transfer,
+            })
+            .args(multisig_lite::instruction::Revoke {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,500 @@
+//! `multisig_list::multisig_list::set_roles` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+use multisig_lite::Role;
+
+#[tokio::test]
+async fn set_roles() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Then makes the last signer a proposer only by the 3 out of 5 signers.
+    assert!(tester
+        .with_signature()
+        .set_roles(4, Role::PROPOSER, 0, &[1, 2])
+        .await
+        .is_ok());
+
+    let state = tester.get_state_account().await;
+    assert_eq!(
+        state.roles,
+        vec![Role::ALL, Role::ALL, Role::ALL, Role::ALL, Role::PROPOSER],
+    );
+}
+
+#[tokio::test]
+async fn set_roles_without_quorum() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .with_signature()
+        .set_roles(4, Role::PROPOSER, 0, &[1])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn set_roles_with_proposer_only_cosigner() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account, with the proposer only signer.
+    tester.create().await;
+    tester
+        .with_signature()
+        .set_roles(4, Role::PROPOSER, 0, &[1, 2])
+        .await
+        .unwrap();
+
+    // The proposer only co-signer doesn't count for the quorum.
+    let err = tester
+        .with_signature()
+        .set_roles(3, Role::PROPOSER, 0, &[1, 4])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6015)),
+    );
+}
+
+#[tokio::test]
+async fn set_roles_below_threshold() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    // Leaves the 3 approvers, which is the threshold.
+    for signer in [3, 4] {
+        assert!(tester
+            .with_signature()
+            .set_roles(signer, Role::PROPOSER, 0, &[1, 2])
+            .await
+            .is_ok());
+    }
+
+    // Then tries to take the approver role from one more.
+    let err = tester
+        .with_signature()
+        .set_roles(2, Role::PROPOSER, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6010)),
+    );
+}
+
+#[tokio::test]
+async fn create_transfer_by_approver_only() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account, with the approver only signer.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .with_signature()
+        .set_roles(1, Role::APPROVER, 0, &[1, 2])
+        .await
+        .unwrap();
+
+    let err = tester.create_transfer(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6024)),
+    );
+}
+
+#[tokio::test]
+async fn approve_by_proposer_only() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account, with the proposer only signer.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .with_signature()
+        .set_roles(1, Role::PROPOSER, 0, &[1, 2])
+        .await
+        .unwrap();
+
+    // The proposer can create a transfer but can't approve it.
+    assert!(tester.create_transfer(1).await.is_ok());
+    let err = tester.approve(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6025)),
+    );
+}
+
+#[tokio::test]
+async fn execute_by_non_executor() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account, with the non-executor signer.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .with_signature()
+        .set_roles(1, Role::PROPOSER | Role::APPROVER, 0, &[1, 2])
+        .await
+        .unwrap();
+
+    // Creates and approves a transfer by the m signers.
+    assert!(tester.create_transfer(0).await.is_ok());
+    for signer in 0..tester.m as usize {
+        assert!(tester.approve(signer).await.is_ok());
+    }
+
+    // Only the executor can execute it.
+    let err = tester.execute(1).await.err().unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6026)),
+    );
+    assert!(tester.execute(0).await.is_ok());
+    assert_eq!(
+        tester.client.get_balance(tester.recipient).await.unwrap(),
+        LAMPORTS_PER_SOL,
+    );
+}
+
+#[tokio::test]
+async fn set_roles_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates a multisig account.
+    tester.create().await;
+
+    let err = tester
+        .set_roles(4, Role::PROPOSER, 0, &[1, 2])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+    transfer: Pubkey,
+    recipient: Pubkey,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+        let (transfer, _) = Pubkey::find_program_address(
+            &[b"transfer", state_pda.as_ref(), &0u64.to_le_bytes()],
+            &pid,
+        );
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 10 * LAMPORTS_PER_SOL,
+            transfer,
+            recipient: Pubkey::new_unique(),
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn create(&mut self) {
+        let mut ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        // Gives some SOL to the other signers to pay the transfer account rent.
+        for signer in &self.signers[1..] {
+            ixs.push(system_instruction::transfer(
+                &self.funder.pubkey(),
+                &signer.pubkey(),
+                LAMPORTS_PER_SOL,
+            ));
+        }
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn set_roles(
+        &mut self,
+        pubkey_index: usize,
+        roles: u8,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let pubkey = self.signers[pubkey_index].pubkey();
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::SetRoles {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+            })
+            .args(multisig_lite::instruction::SetRoles { pubkey, roles })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+
+    async fn create_transfer(
+        &mut self,
+        creator_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let creator = self.signers[creator_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTransfer {
+                creator: creator.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: self.transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTransfer {
+                recipient: self.recipient,
+                lamports: LAMPORTS_PER_SOL,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if creator.pubkey() != self.funder.pubkey() {
+            signers.push(creator.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn approve(
+        &mut self,
+        signer_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Approve {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                transfer: self.transfer,
+            })
+            .args(multisig_lite::instruction::Approve {})
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn execute(
+        &mut self,
+        executor_index: usize,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let executor = self.signers[executor_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Execute {
+                executor: executor.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer: self.transfer,
+                recipient: self.recipient,
+                memo_program: multisig_lite::memo::ID,
+            })
+            .args(multisig_lite::instruction::Execute {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if executor.pubkey() != self.funder.pubkey() {
+            signers.push(executor.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+}
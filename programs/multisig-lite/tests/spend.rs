@@ -0,0 +1,370 @@
+//! `multisig_list::multisig_list::spend` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn spend() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the spending limit.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .create_spending_limit(1, 2 * LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await;
+
+    // Then spends within the limit without the approvals.
+    let recipient = Pubkey::new_unique();
+    assert!(tester
+        .with_signature()
+        .spend(1, recipient, LAMPORTS_PER_SOL)
+        .await
+        .is_ok());
+
+    assert_eq!(tester.get_balance(recipient).await, LAMPORTS_PER_SOL);
+    let limit = tester.get_limit_account().await;
+    assert_eq!(limit.spent, LAMPORTS_PER_SOL);
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, tester.lamports - LAMPORTS_PER_SOL);
+    assert!(state.queue.is_empty());
+}
+
+#[tokio::test]
+async fn spend_over_the_limit() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the spending limit.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .create_spending_limit(1, 2 * LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await;
+
+    // Spends up to the limit, with the different amounts to avoid
+    // the duplicate transactions.
+    let recipient = Pubkey::new_unique();
+    for lamports in [LAMPORTS_PER_SOL / 2, 3 * LAMPORTS_PER_SOL / 2] {
+        assert!(tester
+            .with_signature()
+            .spend(1, recipient, lamports)
+            .await
+            .is_ok());
+    }
+
+    // Then exceeds the limit in the same period.
+    let err = tester
+        .with_signature()
+        .spend(1, recipient, 1)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6022)),
+    );
+}
+
+#[tokio::test]
+async fn spend_by_other_signer() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the spending limit.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .create_spending_limit(1, 2 * LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await;
+
+    // The third signer can't spend with the spending limit of the second signer.
+    let err = tester
+        .with_signature()
+        .spend(2, Pubkey::new_unique(), LAMPORTS_PER_SOL)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(6023)),
+    );
+}
+
+#[tokio::test]
+async fn spend_without_signature() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account with the spending limit.
+    tester.create().await;
+    tester.fund().await;
+    tester
+        .create_spending_limit(1, 2 * LAMPORTS_PER_SOL, 0, &[1, 2])
+        .await;
+
+    let err = tester
+        .spend(1, Pubkey::new_unique(), LAMPORTS_PER_SOL)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.unwrap(), TransactionError::SignatureFailure);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    with_signature: bool,
+    m: u8,
+    signers: Vec<std::rc::Rc<Keypair>>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+    limit_pda: Pubkey,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.clone(),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+            std::rc::Rc::new(Keypair::new()),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        // The spending limit of the second signer.
+        let (limit_pda, _limit_bump) = Pubkey::find_program_address(
+            &[b"limit", state_pda.as_ref(), signers[1].pubkey().as_ref()],
+            &pid,
+        );
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            with_signature: false,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 100 * LAMPORTS_PER_SOL,
+            limit_pda,
+        }
+    }
+
+    fn with_signature(&mut self) -> &mut Self {
+        self.with_signature = true;
+        self
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        self.client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::State::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_limit_account(&mut self) -> multisig_lite::SpendingLimit {
+        self.client
+            .get_account_with_commitment(self.limit_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .map(|account| {
+                let mut data: &[u8] = &account.data;
+                multisig_lite::SpendingLimit::try_deserialize(&mut data).unwrap()
+            })
+            .unwrap()
+    }
+
+    async fn get_balance(&mut self, key: Pubkey) -> u64 {
+        self.client.get_balance(key).await.unwrap()
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.iter().map(|signer| signer.pubkey()).collect(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_spending_limit(
+        &mut self,
+        spender_index: usize,
+        lamports: u64,
+        signer_index: usize,
+        cosigner_indexes: &[usize],
+    ) {
+        let signer = self.signers[signer_index].clone();
+        let cosigners: Vec<_> = cosigner_indexes
+            .iter()
+            .map(|index| self.signers[*index].clone())
+            .collect();
+
+        // Co-signers for the m-of-n quorum.
+        let remaining_accounts: Vec<_> = cosigners
+            .iter()
+            .map(|cosigner| AccountMeta {
+                pubkey: cosigner.pubkey(),
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateSpendingLimit {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                limit: self.limit_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateSpendingLimit {
+                pubkey: self.signers[spender_index].pubkey(),
+                lamports,
+                period: multisig_lite::Period::Day,
+            })
+            .accounts(remaining_accounts)
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        for signer in std::iter::once(&signer).chain(&cosigners) {
+            if signer.pubkey() != self.funder.pubkey() {
+                signers.push(signer.as_ref());
+            }
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&signers, self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn spend(
+        &mut self,
+        signer_index: usize,
+        recipient: Pubkey,
+        lamports: u64,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let signer = self.signers[signer_index].clone();
+
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Spend {
+                signer: signer.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                limit: self.limit_pda,
+                recipient,
+            })
+            .args(multisig_lite::instruction::Spend {
+                lamports,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut signers = vec![self.funder.as_ref()];
+        if signer.pubkey() != self.funder.pubkey() {
+            signers.push(signer.as_ref());
+        }
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        if self.with_signature {
+            tx.sign(&signers, self.recent_blockhash);
+        }
+        self.client.process_transaction(tx).await
+    }
+}
@@ -0,0 +1,268 @@
+//! `multisig_list::multisig_list::sync_balance` instruction tests.
+
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+use anchor_client::anchor_lang::AccountDeserialize;
+
+#[tokio::test]
+async fn sync_balance() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    // Sends lamports directly to the fund account.
+    tester.transfer_directly(5 * LAMPORTS_PER_SOL).await;
+
+    // The direct transfer is not accounted yet.
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, 10 * LAMPORTS_PER_SOL);
+
+    // Synchronizes the balance.
+    assert!(tester.sync_balance().await.is_ok());
+    let state = tester.get_state_account().await;
+    assert_eq!(state.balance, 15 * LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn sync_balance_with_pending_transfers() {
+    let mut tester = Tester::new().await;
+
+    // Creates and funds a multisig account.
+    tester.create().await;
+    tester.fund().await;
+
+    // Queues a transfer.
+    let transfer = tester.transfer_pda(0);
+    let recipient = Pubkey::new_unique();
+    assert!(tester
+        .create_transfer(transfer, recipient, 3 * LAMPORTS_PER_SOL)
+        .await
+        .is_ok());
+    let state = tester.get_state_account().await;
+    assert_eq!(state.pending, 3 * LAMPORTS_PER_SOL);
+
+    // Synchronizes the balance, keeping the pending lamports reserved.
+    assert!(tester.sync_balance().await.is_ok());
+    let rent = tester.client.get_rent().await.unwrap().minimum_balance(0);
+    let fund_lamports = tester.get_fund_lamports().await;
+    let state = tester.get_state_account().await;
+    assert_eq!(state.pending, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(state.balance, fund_lamports - rent - 3 * LAMPORTS_PER_SOL);
+}
+
+struct Tester {
+    program: anchor_client::Program,
+    client: solana_program_test::BanksClient,
+    funder: std::rc::Rc<Keypair>,
+    recent_blockhash: Hash,
+    m: u8,
+    signers: Vec<Pubkey>,
+    q: u8,
+    index: u64,
+    state_pda: Pubkey,
+    state_bump: u8,
+    fund_pda: Pubkey,
+    fund_bump: u8,
+    lamports: u64,
+}
+
+impl Tester {
+    async fn new() -> Self {
+        let pid = multisig_lite::id();
+        let (client, funder, recent_blockhash) = solana_program_test::ProgramTest::new(
+            "multisig_lite",
+            pid,
+            solana_program_test::processor!(multisig_lite::entry),
+        )
+        .start()
+        .await;
+
+        // Wrap the founder keypair to be able to be passed to the anchor program.
+        let funder = std::rc::Rc::new(funder);
+
+        // Default 5 signers, including the funder.
+        let signers = vec![
+            funder.pubkey(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        // Creates an anchor::Program for `solana_sdk::instruction::Instruction`s.
+        let cluster = anchor_client::Cluster::Localnet;
+        let program = anchor_client::Client::new(cluster, funder.clone()).program(pid);
+
+        // Find PDAs.
+        let index = 0u64;
+        let (state_pda, state_bump) = Pubkey::find_program_address(
+            &[b"state", funder.pubkey().as_ref(), &index.to_le_bytes()],
+            &pid,
+        );
+        let (fund_pda, fund_bump) =
+            Pubkey::find_program_address(&[b"fund", state_pda.as_ref()], &pid);
+
+        Self {
+            program,
+            client,
+            funder,
+            recent_blockhash,
+            m: 3,
+            signers,
+            q: 10,
+            index,
+            state_pda,
+            state_bump,
+            fund_pda,
+            fund_bump,
+            lamports: 10 * LAMPORTS_PER_SOL,
+        }
+    }
+
+    async fn get_state_account(&mut self) -> multisig_lite::State {
+        let account = self
+            .client
+            .get_account_with_commitment(self.state_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut data: &[u8] = &account.data;
+        multisig_lite::State::try_deserialize(&mut data).unwrap()
+    }
+
+    async fn get_fund_lamports(&mut self) -> u64 {
+        self.client
+            .get_account_with_commitment(self.fund_pda, CommitmentLevel::Processed)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports
+    }
+
+    fn transfer_pda(&self, index: u64) -> Pubkey {
+        let (transfer_pda, _) = Pubkey::find_program_address(
+            &[b"transfer", self.state_pda.as_ref(), &index.to_le_bytes()],
+            &self.program.id(),
+        );
+        transfer_pda
+    }
+
+    async fn create(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Create {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Create {
+                m: self.m,
+                signers: self.signers.clone(),
+                weights: vec![1; self.signers.len()],
+                q: self.q,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn fund(&mut self) {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::Fund {
+                funder: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::Fund {
+                lamports: self.lamports,
+                _index: self.index,
+                _state_bump: self.state_bump,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn create_transfer(
+        &mut self,
+        transfer: Pubkey,
+        recipient: Pubkey,
+        lamports: u64,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::CreateTransfer {
+                creator: self.funder.pubkey(),
+                state: self.state_pda,
+                fund: self.fund_pda,
+                transfer,
+                system_program: system_program::id(),
+            })
+            .args(multisig_lite::instruction::CreateTransfer {
+                recipient,
+                lamports,
+                execute_after: None,
+                expire_at: None,
+                memo: None,
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+
+    async fn transfer_directly(&mut self, lamports: u64) {
+        let ix = system_instruction::transfer(&self.funder.pubkey(), &self.fund_pda, lamports);
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await.unwrap();
+    }
+
+    async fn sync_balance(&mut self) -> Result<(), solana_program_test::BanksClientError> {
+        let ixs = self
+            .program
+            .request()
+            .accounts(multisig_lite::accounts::SyncBalance {
+                state: self.state_pda,
+                fund: self.fund_pda,
+            })
+            .args(multisig_lite::instruction::SyncBalance {
+                fund_bump: self.fund_bump,
+            })
+            .instructions()
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&self.funder.pubkey()));
+        tx.sign(&[self.funder.as_ref()], self.recent_blockhash);
+        self.client.process_transaction(tx).await
+    }
+}
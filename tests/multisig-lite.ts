@@ -3,6 +3,9 @@ import { web3, Program } from "@project-serum/anchor";
 import { MultisigLite } from "../target/types/multisig_lite";
 import { expect } from "chai";
 const { Keypair, PublicKey, LAMPORTS_PER_SOL } = web3;
+const MEMO_PROGRAM_ID = new PublicKey(
+  "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
 
 describe("multisig-lite", () => {
   // Configure the client to use the local cluster.
@@ -12,8 +15,13 @@ describe("multisig-lite", () => {
   // Prepares for the multisig PDAs.
   const program = anchor.workspace.MultisigLite as Program<MultisigLite>;
   const wallet = provider.wallet;
+  const index = new anchor.BN(0);
   const [state, stateBump] = PublicKey.findProgramAddressSync(
-    [anchor.utils.bytes.utf8.encode("state"), wallet.publicKey.toBuffer()],
+    [
+      anchor.utils.bytes.utf8.encode("state"),
+      wallet.publicKey.toBuffer(),
+      index.toArrayLike(Buffer, "le", 8),
+    ],
     program.programId
   );
   const [fund, fundBump] = PublicKey.findProgramAddressSync(
@@ -29,6 +37,9 @@ describe("multisig-lite", () => {
   }
   const queueDepth = 100;
 
+  // Other signers to meet the m-of-n quorum with the first signer.
+  const cosigners = () => signers.slice(1, threshold);
+
   // 10 different payees.
   const payees = [];
   for (let i = 0; i < 10; i++) {
@@ -55,7 +66,9 @@ describe("multisig-lite", () => {
       .create(
         threshold,
         signers.map((pair) => pair.publicKey),
+        Buffer.from(signers.map(() => 1)),
         queueDepth,
+        index,
         stateBump,
         fundBump
       )
@@ -74,23 +87,35 @@ describe("multisig-lite", () => {
     // it interfears the later tests.
     try {
       const ms = await program.account.state.fetch(state);
-      const remainingAccounts = ms.queue.map((transfer) => {
-        return {
-          pubkey: transfer,
-          isWritable: true,
-          isSigner: false,
-        };
-      });
+      const remainingAccounts = cosigners()
+        .map((signer) => {
+          return {
+            pubkey: signer.publicKey,
+            isWritable: false,
+            isSigner: true,
+          };
+        })
+        .concat(
+          ms.queue.map((transfer) => {
+            return {
+              pubkey: transfer,
+              isWritable: true,
+              isSigner: false,
+            };
+          })
+        );
 
       await program.methods
-        .close(stateBump, fundBump)
+        .close(index, stateBump, fundBump)
         .accounts({
+          signer: signers[0].publicKey,
           funder: wallet.publicKey,
           state,
           fund,
+          destination: wallet.publicKey,
         })
         .remainingAccounts(remainingAccounts)
-        .signers([wallet.payer])
+        .signers(signers.slice(0, threshold))
         .rpc();
     } catch (e) {
       // ignore the close error.
@@ -118,21 +143,29 @@ describe("multisig-lite", () => {
     expect(ms.signers).to.include.deep.members(
       signers.map((pair) => pair.publicKey)
     );
-    for (let signed of ms.signed) {
-      expect(signed).to.be.false;
-    }
     expect(ms.queue).to.have.lengthOf(0);
   });
 
   it("Checks the account closure", async () => {
     await program.methods
-      .close(stateBump, fundBump)
+      .close(index, stateBump, fundBump)
       .accounts({
+        signer: signers[0].publicKey,
         funder: wallet.publicKey,
         state,
         fund,
+        destination: wallet.publicKey,
       })
-      .signers([wallet.payer])
+      .remainingAccounts(
+        cosigners().map((signer) => {
+          return {
+            pubkey: signer.publicKey,
+            isWritable: false,
+            isSigner: true,
+          };
+        })
+      )
+      .signers(signers.slice(0, threshold))
       .rpc();
 
     try {
@@ -147,7 +180,7 @@ describe("multisig-lite", () => {
     const before = await provider.connection.getBalance(fund);
     const lamports = 1000000 * LAMPORTS_PER_SOL;
     await program.methods
-      .fund(new anchor.BN(lamports), stateBump, fundBump)
+      .fund(new anchor.BN(lamports), index, stateBump, fundBump)
       .accounts({
         funder: wallet.publicKey,
         state,
@@ -167,7 +200,7 @@ describe("multisig-lite", () => {
   it("Checks multiple queued transactions", async () => {
     let balance = 1000000 * LAMPORTS_PER_SOL;
     await program.methods
-      .fund(new anchor.BN(balance), stateBump, fundBump)
+      .fund(new anchor.BN(balance), index, stateBump, fundBump)
       .accounts({
         funder: wallet.publicKey,
         state,
@@ -177,22 +210,32 @@ describe("multisig-lite", () => {
       .rpc();
 
     for (const [index, payee] of payees.entries()) {
-      const transfer = Keypair.generate();
+      const { transfers } = await program.account.state.fetch(state);
+      const [transfer] = PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("transfer"),
+          state.toBuffer(),
+          transfers.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
       const lamports = 100 * index * LAMPORTS_PER_SOL;
       const lamportsBN = new anchor.BN(lamports);
       const signer = signers[index % signers.length];
       const tx = await program.methods
-        .createTransfer(payee.publicKey, lamportsBN, fundBump)
+        .createTransfer(payee.publicKey, lamportsBN, null, null, null, fundBump)
         .accounts({
           creator: signer.publicKey,
           state,
           fund,
-          transfer: transfer.publicKey,
+          transfer,
         })
-        .signers([signer, transfer])
+        .signers([signer])
         .rpc();
 
-      balance -= lamports;
+      // The fund gives back the rent fee to the creator, too.
+      const rent = await provider.connection.getBalance(transfer);
+      balance -= lamports + rent;
     }
 
     // Checks the queue state as well as the balance of the
@@ -211,7 +254,7 @@ describe("multisig-lite", () => {
   it("Checks the approval and the transfer execution", async () => {
     let balance = 1000000 * LAMPORTS_PER_SOL;
     await program.methods
-      .fund(new anchor.BN(balance), stateBump, fundBump)
+      .fund(new anchor.BN(balance), index, stateBump, fundBump)
       .accounts({
         funder: wallet.publicKey,
         state,
@@ -221,66 +264,68 @@ describe("multisig-lite", () => {
       .rpc();
 
     for (const [index, payee] of payees.entries()) {
-      const transfer = Keypair.generate();
+      const { transfers } = await program.account.state.fetch(state);
+      const [transfer] = PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("transfer"),
+          state.toBuffer(),
+          transfers.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
       const lamports = 1000 * index * LAMPORTS_PER_SOL;
       const lamportsBN = new anchor.BN(lamports);
       const signer = signers[index % signers.length];
       const tx = await program.methods
-        .createTransfer(payee.publicKey, lamportsBN, fundBump)
+        .createTransfer(payee.publicKey, lamportsBN, null, null, null, fundBump)
         .accounts({
           creator: signer.publicKey,
           state,
           fund,
-          transfer: transfer.publicKey,
+          transfer,
         })
-        .signers([signer, transfer])
+        .signers([signer])
         .rpc();
     }
 
     let ms = await program.account.state.fetch(state);
     expect(ms.queue).to.have.lengthOf(payees.length);
-    expect(ms.signed.filter((signed) => signed)).to.have.lengthOf(0);
 
+    // 3/3 approval for each transfer.
+    //
     // We need both the transfer as well as the
     // payee account information to make the
     // transfer executed.
-    //
-    // This should be abstructed by SDK.
-    const remainingAccounts = payees
-      .map((payee) => {
-        return {
-          pubkey: payee.publicKey,
-          isWritable: true,
-          isSigner: false,
-        };
-      })
-      .concat(
-        ms.queue.map((transfer) => {
-          return {
-            pubkey: transfer,
-            isWritable: true,
-            isSigner: false,
-          };
-        })
-      );
-
-    // 3/3 approval.
-    for (let i = 0; i < threshold; i++) {
+    for (const transfer of ms.queue) {
+      const tx = await program.account.transfer.fetch(transfer);
+      expect(tx.signed.filter(Boolean)).to.have.lengthOf(0);
+      for (let i = 0; i < threshold; i++) {
+        await program.methods
+          .approve()
+          .accounts({
+            signer: signers[i].publicKey,
+            state,
+            transfer,
+          })
+          .signers([signers[i]])
+          .rpc();
+      }
       await program.methods
-        .approve(fundBump)
+        .execute(fundBump)
         .accounts({
-          signer: signers[i].publicKey,
+          executor: signers[0].publicKey,
           state,
           fund,
+          transfer,
+          recipient: tx.recipient,
+          memoProgram: MEMO_PROGRAM_ID,
         })
-        .remainingAccounts(remainingAccounts)
-        .signers([signers[i]])
+        .signers([signers[0]])
         .rpc();
     }
 
-    // Checks The queue is empty and the signer state reset.
+    // Checks The queue is empty.
     ms = await program.account.state.fetch(state);
-    expect(ms.signed.filter(Boolean)).to.have.lengthOf(0);
     expect(ms.queue).to.have.lengthOf(0);
 
     // And transfer to the payees.